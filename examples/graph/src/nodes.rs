@@ -1,4 +1,4 @@
-use qmetaobject::scenegraph::SGNode;
+use qmetaobject::scenegraph::{DrawingMode, GeometryNode, Point2D, SGNode};
 use qmetaobject::{qrc, QQuickItem};
 use qttypes::{QColor, QRectF};
 
@@ -24,7 +24,6 @@ qrc! {
 cpp! {{
     #include <QtQuick/QQuickItem>
 
-    #include "src/gridnode.cpp"
     #include "src/linenode.cpp"
     #include "src/noisynode.cpp"
 }}
@@ -48,12 +47,32 @@ pub fn noisy_node_set_rect(s: &mut SGNode<NoisyNode>, rect: QRectF) {
     });
 }
 
-pub enum GridNode {}
+pub type GridNode = GeometryNode<Point2D>;
+
+const GRID_SIZE: f64 = 32.;
+
+/// Draws a fixed set of grid lines, scaled to the bounding rect.
 pub fn update_grid_node(s: &mut SGNode<GridNode>, rect: QRectF) {
-    cpp!(unsafe [s as "GridNode**", rect as "QRectF"] {
-        if (!*s) *s = new GridNode;
-        (*s)->setRect(rect);
-    });
+    s.create(DrawingMode::Lines);
+    s.set_color(QColor::from_name("gray"));
+
+    let v_count = ((rect.width - 1.) / GRID_SIZE) as usize;
+    let h_count = ((rect.height - 1.) / GRID_SIZE) as usize;
+    let (x, y, w, h) = (rect.x as f32, rect.y as f32, rect.width as f32, rect.height as f32);
+    let mut vertices = Vec::with_capacity((v_count + h_count) * 2);
+    // First write the vertical lines
+    for i in 0..v_count {
+        let dx = ((i + 1) as f64 * GRID_SIZE) as f32;
+        vertices.push(Point2D { x: dx, y });
+        vertices.push(Point2D { x: dx, y: y + h });
+    }
+    // Then write the horizontal lines
+    for i in 0..h_count {
+        let dy = ((i + 1) as f64 * GRID_SIZE) as f32;
+        vertices.push(Point2D { x, y: dy });
+        vertices.push(Point2D { x: x + w, y: dy });
+    }
+    s.set_vertices(&vertices);
 }
 
 pub enum LineNode {}
@@ -0,0 +1,205 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Reloading of the QML files when they change, during development.
+//!
+//! Enabled with the `hot_reload` feature.
+
+use cpp::cpp;
+
+use super::*;
+
+cpp! {{
+    #include <QtCore/QDirIterator>
+    #include <QtCore/QFileInfo>
+    #include <QtCore/QFileSystemWatcher>
+    #include <QtCore/QPointer>
+    #include <QtCore/QTimer>
+    #include <QtGui/QWindow>
+    #include <QtQml/QQmlApplicationEngine>
+    #include <QtQml/QQmlContext>
+    #include "qmetaobject_rust.hpp"
+
+    // Defined in qtdeclarative.rs
+    void qmlErrorsToRust(const QList<QQmlError> &errors, void *vec);
+
+    // Child of the engine, found by its object name.
+    struct RustHotReloader : QObject {
+        QQmlApplicationEngine *engine;
+        QString dir;
+        QFileSystemWatcher watcher;
+        QTimer timer;
+        // A `*mut dyn FnMut(Result<(), Vec<QmlError>>)`, or null
+        TraitObject callback {};
+
+        RustHotReloader(QQmlApplicationEngine *engine, const QString &dir)
+            : QObject(engine), engine(engine), dir(dir)
+        {
+            setObjectName(QStringLiteral("qmetaobject_hot_reloader"));
+            // Editors usually write several times, or replace the file
+            timer.setSingleShot(true);
+            timer.setInterval(100);
+            QObject::connect(&timer, &QTimer::timeout, this, [this] { reload(); });
+            QObject::connect(&watcher, &QFileSystemWatcher::fileChanged, this, [this](const QString &path) {
+                // A replaced file is no longer watched
+                if (QFileInfo::exists(path) && !watcher.files().contains(path))
+                    watcher.addPath(path);
+                timer.start();
+            });
+            QObject::connect(&watcher, &QFileSystemWatcher::directoryChanged, this, [this] {
+                watchFiles();
+                timer.start();
+            });
+            watchFiles();
+        }
+
+        ~RustHotReloader() {
+            setCallback({});
+        }
+
+        void setCallback(TraitObject cb) {
+            dropCallback(callback);
+            callback = cb;
+        }
+
+        static void dropCallback(TraitObject cb) {
+            if (cb.isValid())
+                rust!(Rust_RustHotReloader_dropCallback [
+                    cb: *mut dyn FnMut(Result<(), Vec<QmlError>>) as "TraitObject"
+                ] {
+                    drop(Box::from_raw(cb));
+                });
+        }
+
+        void watchFiles() {
+            QStringList paths { dir };
+            QDirIterator it(dir, QStringList { "*.qml", "*.js", "*.mjs", "qmldir" },
+                            QDir::Files | QDir::AllDirs | QDir::NoDotAndDotDot,
+                            QDirIterator::Subdirectories);
+            while (it.hasNext())
+                paths << it.next();
+            QStringList watched = watcher.files() + watcher.directories();
+            for (const QString &path : paths) {
+                if (!watched.contains(path))
+                    watcher.addPath(path);
+            }
+        }
+
+        void reload() {
+            struct Root { QUrl url; QRect geometry; bool isWindow; };
+            // The geometry of the windows is restored after reloading
+            QVector<Root> roots;
+            for (QObject *obj : engine->rootObjects()) {
+                QQmlContext *context = QQmlEngine::contextForObject(obj);
+                QUrl url = context ? context->baseUrl() : QUrl();
+                // Objects loaded from data cannot be reloaded
+                if (url.isEmpty() || !url.isLocalFile())
+                    continue;
+                auto window = qobject_cast<QWindow *>(obj);
+                roots.append({ url, window ? window->geometry() : QRect(), window != nullptr });
+                delete obj;
+            }
+            engine->clearComponentCache();
+
+            QList<QQmlError> warnings;
+            auto connection = QObject::connect(engine, &QQmlEngine::warnings, [&](const QList<QQmlError> &w) {
+                warnings += w;
+            });
+            bool ok = true;
+            for (const Root &root : roots) {
+                int count = engine->rootObjects().size();
+                engine->load(root.url);
+                if (engine->rootObjects().size() == count) {
+                    ok = false;
+                    continue;
+                }
+                auto window = qobject_cast<QWindow *>(engine->rootObjects().last());
+                if (window && root.isWindow)
+                    window->setGeometry(root.geometry);
+            }
+            QObject::disconnect(connection);
+            ok = ok && warnings.isEmpty();
+
+            if (!callback.isValid())
+                return;
+            void *errors = rust!(Rust_RustHotReloader_newErrors [] -> *mut Vec<QmlError> as "void *" {
+                Box::into_raw(Box::new(Vec::new()))
+            });
+            qmlErrorsToRust(warnings, errors);
+            // The callback may set another callback or destroy the reloader, so it is taken out
+            // during the call, and only put back if it was not replaced.
+            TraitObject cb = callback;
+            callback = {};
+            QPointer<RustHotReloader> self = this;
+            rust!(Rust_RustHotReloader_reloaded [
+                cb: &mut dyn FnMut(Result<(), Vec<QmlError>>) as "TraitObject",
+                ok: bool as "bool",
+                errors: *mut Vec<QmlError> as "void *"
+            ] {
+                let errors = *Box::from_raw(errors);
+                cb(if ok { Ok(()) } else { Err(errors) });
+            });
+            if (self && !self->callback.isValid())
+                self->callback = cb;
+            else
+                dropCallback(cb);
+        }
+    };
+}}
+
+impl QmlEngine {
+    /// Reloads the QML files loaded with `load_file` or `load_url` when a QML or JavaScript
+    /// file in `dir`, or one of its sub-directories, changes.
+    ///
+    /// The root objects are destroyed and loaded again after clearing the component cache.
+    /// The context properties, including the objects set with `set_object_property`, are
+    /// preserved. Windows keep their geometry.
+    ///
+    /// Use `on_hot_reload` to get the errors. This is meant for development, and requires
+    /// the `hot_reload` feature.
+    pub fn enable_hot_reload(&self, dir: QString) {
+        let engine = self.cpp_ptr();
+        cpp!(unsafe [engine as "QQmlApplicationEngine *", dir as "QString"] {
+            auto previous = engine->findChild<QObject *>(QStringLiteral("qmetaobject_hot_reloader"),
+                                                        Qt::FindDirectChildrenOnly);
+            delete static_cast<RustHotReloader *>(previous);
+            new RustHotReloader(engine, dir);
+        })
+    }
+
+    /// Sets a callback called after every reload, with the errors and warnings reported
+    /// while loading the files again
+    ///
+    /// Has no effect if `enable_hot_reload` was not called.
+    pub fn on_hot_reload<F: FnMut(Result<(), Vec<QmlError>>) + 'static>(&self, callback: F) {
+        let callback: *mut dyn FnMut(Result<(), Vec<QmlError>>) = Box::into_raw(Box::new(callback));
+        let engine = self.cpp_ptr();
+        cpp!(unsafe [engine as "QQmlApplicationEngine *", callback as "TraitObject"] {
+            auto reloader = engine->findChild<QObject *>(QStringLiteral("qmetaobject_hot_reloader"),
+                                                        Qt::FindDirectChildrenOnly);
+            if (reloader) {
+                static_cast<RustHotReloader *>(reloader)->setCallback(callback);
+            } else {
+                rust!(Rust_QmlEngine_onHotReload_drop [
+                    callback: *mut dyn FnMut(Result<(), Vec<QmlError>>) as "TraitObject"
+                ] {
+                    drop(Box::from_raw(callback));
+                });
+            }
+        })
+    }
+}
@@ -0,0 +1,292 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Image providers, to serve `image://` URLs to QML from Rust.
+
+use cpp::{cpp, cpp_class};
+
+use super::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QCoreApplication>
+    #include <QtCore/QMutex>
+    #include <QtQml/QQmlEngine>
+    #include <QtQuick/QQuickImageProvider>
+    #include "qmetaobject_rust.hpp"
+
+    static QSize requestedSizeToRust(const QSize &requestedSize) {
+        // An invalid size, or a negative dimension, means that there is no requested size
+        return QSize(qMax(0, requestedSize.width()), qMax(0, requestedSize.height()));
+    }
+
+    struct RustImageProvider : QQuickImageProvider {
+        TraitObject provider;
+
+        explicit RustImageProvider(TraitObject provider)
+            : QQuickImageProvider(QQuickImageProvider::Image), provider(provider) {}
+
+        ~RustImageProvider() {
+            rust!(Rust_RustImageProvider_drop [provider: *mut dyn QmlImageProvider as "TraitObject"] {
+                drop(Box::from_raw(provider));
+            });
+        }
+
+        QImage requestImage(const QString &id, QSize *size, const QSize &requestedSize) override {
+            QSize requested_size = requestedSizeToRust(requestedSize);
+            return rust!(Rust_RustImageProvider_requestImage [
+                provider: &dyn QmlImageProvider as "TraitObject",
+                id: QString as "QString",
+                requested_size: QSize as "QSize",
+                size: *mut QSize as "QSize *"
+            ] -> QImage as "QImage" {
+                let (image, original_size) = provider.request_image(id, requested_size);
+                if !size.is_null() {
+                    *size = original_size;
+                }
+                image
+            });
+        }
+    };
+
+    struct RustImageResponse;
+
+    // Shared between the response and the QmlImageResponse handles, which can be in other threads
+    struct RustImageResponseState {
+    #if QT_VERSION >= QT_VERSION_CHECK(5, 14, 0)
+        QRecursiveMutex mutex;
+    #else
+        QMutex mutex { QMutex::Recursive };
+    #endif
+        RustImageResponse *response = nullptr;
+        QImage image;
+        QString error;
+        bool done = false;
+        bool cancelled = false;
+
+        void finish(const QImage &image, const QString &error);
+    };
+
+    struct RustImageResponse : QQuickImageResponse {
+        std::shared_ptr<RustImageResponseState> state = std::make_shared<RustImageResponseState>();
+
+        RustImageResponse() {
+            state->response = this;
+        }
+
+        ~RustImageResponse() {
+            QMutexLocker lock(&state->mutex);
+            state->response = nullptr;
+        }
+
+        QQuickTextureFactory *textureFactory() const override {
+            QMutexLocker lock(&state->mutex);
+            return QQuickTextureFactory::textureFactoryForImage(state->image);
+        }
+
+        QString errorString() const override {
+            QMutexLocker lock(&state->mutex);
+            return state->error;
+        }
+
+        void cancel() override {
+            QMutexLocker lock(&state->mutex);
+            state->cancelled = true;
+        }
+    };
+
+    void RustImageResponseState::finish(const QImage &image, const QString &error) {
+        QMutexLocker lock(&mutex);
+        if (done)
+            return;
+        done = true;
+        this->image = image;
+        this->error = error;
+        // The lock keeps the response alive while the signal is emitted
+        if (response)
+            Q_EMIT response->finished();
+    }
+
+    struct RustImageRequestEvent : QEvent {
+        QString id;
+        QSize requestedSize;
+        std::shared_ptr<RustImageResponseState> state;
+
+        RustImageRequestEvent(const QString &id, const QSize &requestedSize,
+                              std::shared_ptr<RustImageResponseState> state)
+            : QEvent(QEvent::User), id(id), requestedSize(requestedSize), state(std::move(state)) {}
+    };
+
+    // Lives in the main thread, where the Rust provider is called.
+    struct RustImageRequestDispatcher : QObject {
+        TraitObject provider;
+
+        explicit RustImageRequestDispatcher(TraitObject provider) : provider(provider) {}
+
+        ~RustImageRequestDispatcher() {
+            rust!(Rust_RustAsyncImageProvider_drop [provider: *mut dyn QmlAsyncImageProvider as "TraitObject"] {
+                drop(Box::from_raw(provider));
+            });
+        }
+
+        void customEvent(QEvent *e) override {
+            auto event = static_cast<RustImageRequestEvent *>(e);
+            {
+                QMutexLocker lock(&event->state->mutex);
+                if (event->state->cancelled)
+                    return;
+            }
+            QString id = event->id;
+            QSize requested_size = requestedSizeToRust(event->requestedSize);
+            std::shared_ptr<RustImageResponseState> response = event->state;
+            rust!(Rust_RustAsyncImageProvider_requestImageResponse [
+                provider: &dyn QmlAsyncImageProvider as "TraitObject",
+                id: QString as "QString",
+                requested_size: QSize as "QSize",
+                response: QmlImageResponse as "std::shared_ptr<RustImageResponseState>"
+            ] {
+                provider.request_image_response(id, requested_size, response);
+            });
+        }
+    };
+
+    struct RustAsyncImageProvider : QQuickAsyncImageProvider {
+        RustImageRequestDispatcher *dispatcher;
+
+        explicit RustAsyncImageProvider(TraitObject provider)
+            : dispatcher(new RustImageRequestDispatcher(provider)) {}
+
+        ~RustAsyncImageProvider() {
+            delete dispatcher;
+        }
+
+        QQuickImageResponse *requestImageResponse(const QString &id, const QSize &requestedSize) override {
+            // This may be called from a QML loader thread
+            auto response = new RustImageResponse;
+            QCoreApplication::postEvent(dispatcher, new RustImageRequestEvent(id, requestedSize, response->state));
+            return response;
+        }
+    };
+}}
+
+/// Provides images to QML from Rust, for the `image://<id>/<image id>` URLs
+///
+/// Refer to the Qt documentation of QQuickImageProvider. Register the provider with
+/// `QmlEngine::add_image_provider`.
+///
+/// `request_image` may be called from the threads QML uses to load the images, if the
+/// `asynchronous` property of the Image is set. Use QmlAsyncImageProvider to generate the
+/// image in the main thread or with a future.
+pub trait QmlImageProvider: Send + Sync {
+    /// Returns the image with the given id, and the original size of the image
+    ///
+    /// `requested_size` is the `sourceSize` of the Image. A dimension of 0 means that the
+    /// size is not requested in that dimension.
+    fn request_image(&self, id: QString, requested_size: QSize) -> (QImage, QSize);
+}
+
+/// Provides images asynchronously to QML from Rust
+///
+/// Refer to the Qt documentation of QQuickAsyncImageProvider. Register the provider with
+/// `QmlEngine::add_async_image_provider`.
+///
+/// `request_image_response` is always called in the main thread, and the image is loaded
+/// once the QmlImageResponse is finished. It can be finished later from a future
+/// (see `future::execute_async`) or from a worker thread.
+pub trait QmlAsyncImageProvider {
+    /// Starts generating the image with the given id
+    ///
+    /// `requested_size` is the `sourceSize` of the Image. A dimension of 0 means that the
+    /// size is not requested in that dimension.
+    fn request_image_response(
+        &self,
+        id: QString,
+        requested_size: QSize,
+        response: QmlImageResponse,
+    );
+}
+
+cpp_class!(
+    /// Handle to a pending image request of a QmlAsyncImageProvider
+    ///
+    /// It can be sent to another thread. Only the first call to `finish` or `fail` is taken
+    /// into account; the image is never loaded if neither is called.
+    #[derive(Clone)]
+    pub unsafe struct QmlImageResponse as "std::shared_ptr<RustImageResponseState>"
+);
+
+impl QmlImageResponse {
+    /// Completes the request with the given image (emits QQuickImageResponse::finished)
+    pub fn finish(&self, image: QImage) {
+        cpp!(unsafe [self as "const std::shared_ptr<RustImageResponseState> *", image as "QImage"] {
+            (*self)->finish(image, QString());
+        })
+    }
+
+    /// Completes the request with an error, reported by the Image
+    pub fn fail(&self, error: QString) {
+        cpp!(unsafe [self as "const std::shared_ptr<RustImageResponseState> *", error as "QString"] {
+            (*self)->finish(QImage(), error);
+        })
+    }
+
+    /// Returns true if the Image does not need the image anymore (see QQuickImageResponse::cancel)
+    pub fn is_cancelled(&self) -> bool {
+        cpp!(unsafe [self as "const std::shared_ptr<RustImageResponseState> *"] -> bool as "bool" {
+            QMutexLocker lock(&(*self)->mutex);
+            return (*self)->cancelled;
+        })
+    }
+}
+
+impl QmlEngine {
+    /// Registers an image provider for the `image://<id>/` URLs (see QQmlEngine::addImageProvider)
+    ///
+    /// The engine takes ownership of the provider.
+    pub fn add_image_provider<P: QmlImageProvider + 'static>(&mut self, id: QString, provider: P) {
+        let provider: *mut dyn QmlImageProvider = Box::into_raw(Box::new(provider));
+        let engine = self.cpp_ptr();
+        cpp!(unsafe [engine as "QQmlEngine *", id as "QString", provider as "TraitObject"] {
+            engine->addImageProvider(id, new RustImageProvider(provider));
+        })
+    }
+
+    /// Registers an asynchronous image provider for the `image://<id>/` URLs
+    /// (see QQmlEngine::addImageProvider)
+    ///
+    /// The engine takes ownership of the provider.
+    pub fn add_async_image_provider<P: QmlAsyncImageProvider + 'static>(
+        &mut self,
+        id: QString,
+        provider: P,
+    ) {
+        let provider: *mut dyn QmlAsyncImageProvider = Box::into_raw(Box::new(provider));
+        let engine = self.cpp_ptr();
+        cpp!(unsafe [engine as "QQmlEngine *", id as "QString", provider as "TraitObject"] {
+            engine->addImageProvider(id, new RustAsyncImageProvider(provider));
+        })
+    }
+
+    /// Removes the image provider registered with the given id, and destroys it
+    /// (see QQmlEngine::removeImageProvider)
+    pub fn remove_image_provider(&mut self, id: QString) {
+        let engine = self.cpp_ptr();
+        cpp!(unsafe [engine as "QQmlEngine *", id as "QString"] {
+            engine->removeImageProvider(id);
+        })
+    }
+}
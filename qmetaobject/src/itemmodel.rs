@@ -60,6 +60,45 @@ pub trait QAbstractItemModel: QObject {
         HashMap::new()
     }
 
+    /// Refer to the Qt documentation of QAbstractItemModel::canFetchMore
+    fn can_fetch_more(&self, _parent: QModelIndex) -> bool {
+        false
+    }
+
+    /// Refer to the Qt documentation of QAbstractItemModel::fetchMore
+    fn fetch_more(&mut self, _parent: QModelIndex) {}
+
+    /// Refer to the Qt documentation of QAbstractItemModel::headerData
+    ///
+    /// The default implementation calls the one from QAbstractItemModel.
+    fn header_data(&self, section: i32, orientation: Orientation, role: i32) -> QVariant {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [
+            obj as "Rust_QAbstractItemModel *",
+            section as "int",
+            orientation as "Qt::Orientation",
+            role as "int"
+        ] -> QVariant as "QVariant" {
+            return obj ? obj->baseHeaderData(section, orientation, role) : QVariant();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractItemModel::flags
+    ///
+    /// The default implementation calls the one from QAbstractItemModel.
+    fn flags(&self, index: QModelIndex) -> ItemFlags {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [
+            obj as "Rust_QAbstractItemModel *",
+            index as "QModelIndex"
+        ] -> ItemFlags as "Qt::ItemFlags" {
+            return obj ? obj->baseFlags(index) : Qt::ItemFlags();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractItemModel::sort
+    fn sort(&mut self, _column: i32, _order: SortOrder) {}
+
     /// Refer to the Qt documentation of QAbstractListModel::beginInsertRows
     fn begin_insert_rows(&self, parent: QModelIndex, first: i32, last: i32) {
         let obj = self.get_cpp_object();
@@ -171,6 +210,19 @@ pub trait QAbstractItemModel: QObject {
         })
     }
 
+    /// Refer to the Qt documentation of QAbstractItemModel::headerDataChanged
+    fn header_data_changed(&self, orientation: Orientation, first: i32, last: i32) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [
+            obj as "Rust_QAbstractItemModel *",
+            orientation as "Qt::Orientation",
+            first as "int",
+            last as "int"
+        ] {
+            if(obj) obj->headerDataChanged(orientation, first, last);
+        })
+    }
+
     /// Refer to the Qt documentation of QAbstractItemModel::createIndex
     fn create_index(&self, row: i32, column: i32, id: usize) -> QModelIndex {
         let obj = self.get_cpp_object();
@@ -274,5 +326,61 @@ cpp! {{
             });
             return base;
         }
+
+        bool canFetchMore(const QModelIndex &parent) const override {
+            return rust!(Rust_QAbstractItemModel_canFetchMore [
+                rust_object: QObjectPinned<dyn QAbstractItemModel> as "TraitObject",
+                parent: QModelIndex as "QModelIndex"
+            ] -> bool as "bool" {
+                rust_object.borrow().can_fetch_more(parent)
+            });
+        }
+
+        void fetchMore(const QModelIndex &parent) override {
+            rust!(Rust_QAbstractItemModel_fetchMore [
+                rust_object: QObjectPinned<dyn QAbstractItemModel> as "TraitObject",
+                parent: QModelIndex as "QModelIndex"
+            ] {
+                rust_object.borrow_mut().fetch_more(parent)
+            });
+        }
+
+        Qt::ItemFlags flags(const QModelIndex &index) const override {
+            return rust!(Rust_QAbstractItemModel_flags [
+                rust_object: QObjectPinned<dyn QAbstractItemModel> as "TraitObject",
+                index: QModelIndex as "QModelIndex"
+            ] -> ItemFlags as "Qt::ItemFlags" {
+                rust_object.borrow().flags(index)
+            });
+        }
+
+        QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override {
+            return rust!(Rust_QAbstractItemModel_headerData [
+                rust_object: QObjectPinned<dyn QAbstractItemModel> as "TraitObject",
+                section: i32 as "int",
+                orientation: Orientation as "Qt::Orientation",
+                role: i32 as "int"
+            ] -> QVariant as "QVariant" {
+                rust_object.borrow().header_data(section, orientation, role)
+            });
+        }
+
+        void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override {
+            rust!(Rust_QAbstractItemModel_sort [
+                rust_object: QObjectPinned<dyn QAbstractItemModel> as "TraitObject",
+                column: i32 as "int",
+                order: SortOrder as "Qt::SortOrder"
+            ] {
+                rust_object.borrow_mut().sort(column, order)
+            });
+        }
+
+        QVariant baseHeaderData(int section, Qt::Orientation orientation, int role) const {
+            return QAbstractItemModel::headerData(section, orientation, role);
+        }
+
+        Qt::ItemFlags baseFlags(const QModelIndex &index) const {
+            return QAbstractItemModel::flags(index);
+        }
     };
 }}
@@ -0,0 +1,247 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! A JavaScript engine without QML, wrapping QJSEngine.
+
+use cpp::{cpp, cpp_class};
+
+use super::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtQml/QJSEngine>
+    #include <QtCore/QStringList>
+}}
+
+/// An exception thrown by JavaScript code, returned by `JsEngine::evaluate`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JsError {
+    /// The type of error, such as `SyntaxError` or `TypeError`.
+    /// Empty if the thrown value is not an `Error` object.
+    pub name: QString,
+    /// The message of the error, or the thrown value converted to a string.
+    pub message: QString,
+    pub file_name: QString,
+    /// The line number, or 0 if unknown.
+    pub line: i32,
+    /// The JavaScript stack trace, one frame per line.
+    pub stack: QString,
+}
+
+impl JsError {
+    fn from_exception(value: &QJSValue, stack: QString) -> JsError {
+        if !value.is_error() {
+            return JsError { message: value.to_string(), stack, ..Default::default() };
+        }
+        JsError {
+            name: value.property("name".into()).to_string(),
+            message: value.property("message".into()).to_string(),
+            file_name: value.property("fileName".into()).to_string(),
+            line: value.property("lineNumber".into()).to_number() as i32,
+            stack,
+        }
+    }
+}
+
+impl std::fmt::Display for JsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if !self.file_name.is_empty() {
+            write!(f, "{}:", self.file_name)?;
+            if self.line > 0 {
+                write!(f, "{}:", self.line)?;
+            }
+            write!(f, " ")?;
+        }
+        if !self.name.is_empty() {
+            write!(f, "{}: ", self.name)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for JsError {}
+
+/// Bindings for QJSEngine::Extensions flags, see `JsEngine::install_extensions`
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct JsExtensions(pub u32);
+
+impl JsExtensions {
+    /// `qsTr()` and the related functions
+    pub const TRANSLATION: JsExtensions = JsExtensions(0x1);
+    /// The `console` object, logging to the Qt message handler
+    pub const CONSOLE: JsExtensions = JsExtensions(0x2);
+    /// The `gc()` function
+    pub const GARBAGE_COLLECTION: JsExtensions = JsExtensions(0x4);
+    pub const ALL: JsExtensions = JsExtensions(0xffffffff);
+}
+
+impl std::ops::BitOr for JsExtensions {
+    type Output = JsExtensions;
+    fn bitor(self, other: JsExtensions) -> JsExtensions {
+        JsExtensions(self.0 | other.0)
+    }
+}
+
+cpp_class!(
+    /// Wrapper around QJSEngine, to run JavaScript code outside of QML
+    ///
+    /// Unlike QmlEngine, this does not create a QApplication, so it can be used without display.
+    /// The QJSValue created by this engine must not be used after the engine is dropped.
+    pub unsafe struct JsEngine as "std::unique_ptr<QJSEngine>"
+);
+
+impl JsEngine {
+    pub fn new() -> JsEngine {
+        cpp!(unsafe [] -> JsEngine as "std::unique_ptr<QJSEngine>" {
+            return std::unique_ptr<QJSEngine>(new QJSEngine());
+        })
+    }
+
+    /// Evaluates the JavaScript code and returns the value of the last expression
+    /// (see QJSEngine::evaluate)
+    ///
+    /// `file_name` and `line` are used in the error messages and stack traces.
+    /// If the code throws, or if the result is an `Error` object, the error is returned.
+    pub fn evaluate(
+        &mut self,
+        code: QString,
+        file_name: QString,
+        line: i32,
+    ) -> Result<QJSValue, JsError> {
+        let mut stack = QString::default();
+        let stack_ptr = &mut stack;
+        let result = cpp!(unsafe [
+            self as "std::unique_ptr<QJSEngine> *",
+            code as "QString",
+            file_name as "QString",
+            line as "int",
+            stack_ptr as "QString *"
+        ] -> QJSValue as "QJSValue" {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+                // The trace is only filled if there was an exception, which may not be an Error
+                QStringList trace;
+                QJSValue result = (*self)->evaluate(code, file_name, line, &trace);
+                *stack_ptr = trace.join(QLatin1Char('\n'));
+                return result;
+            #else
+                return (*self)->evaluate(code, file_name, line);
+            #endif
+        });
+        if result.is_error() || !stack.is_empty() {
+            Err(JsError::from_exception(&result, stack))
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Loads the JavaScript module at the given path and returns its namespace object
+    /// (see QJSEngine::importModule)
+    #[cfg(qt_5_12)]
+    pub fn import_module(&mut self, file_name: QString) -> Result<QJSValue, JsError> {
+        let result = cpp!(unsafe [
+            self as "std::unique_ptr<QJSEngine> *",
+            file_name as "QString"
+        ] -> QJSValue as "QJSValue" {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+                return (*self)->importModule(file_name);
+            #else
+                return QJSValue();
+            #endif
+        });
+        if result.is_error() {
+            Err(JsError::from_exception(&result, QString::default()))
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Installs the extensions in the global object, or in `object` if it is an object
+    /// (see QJSEngine::installExtensions)
+    pub fn install_extensions(&mut self, extensions: JsExtensions, object: Option<&QJSValue>) {
+        let object = object.cloned().unwrap_or_default();
+        cpp!(unsafe [
+            self as "std::unique_ptr<QJSEngine> *",
+            extensions as "QJSEngine::Extensions",
+            object as "QJSValue"
+        ] {
+            (*self)->installExtensions(extensions, object);
+        })
+    }
+
+    /// Returns the global object of the engine (see QJSEngine::globalObject)
+    pub fn global_object(&self) -> QJSValue {
+        cpp!(unsafe [self as "std::unique_ptr<QJSEngine> *"] -> QJSValue as "QJSValue" {
+            return (*self)->globalObject();
+        })
+    }
+
+    /// Creates a new JavaScript object (see QJSEngine::newObject)
+    pub fn new_object(&self) -> QJSValue {
+        cpp!(unsafe [self as "std::unique_ptr<QJSEngine> *"] -> QJSValue as "QJSValue" {
+            return (*self)->newObject();
+        })
+    }
+
+    /// Creates a new JavaScript array of the given length (see QJSEngine::newArray)
+    pub fn new_array(&self, length: u32) -> QJSValue {
+        cpp!(unsafe [self as "std::unique_ptr<QJSEngine> *", length as "uint"] -> QJSValue as "QJSValue" {
+            return (*self)->newArray(length);
+        })
+    }
+
+    /// Give a QObject to the engine by wrapping it in a QJSValue
+    ///
+    /// This will create the C++ object, which is owned by the JavaScript garbage collector.
+    /// Panic if the C++ object was already created.
+    pub fn new_qobject<T: QObject>(&mut self, obj: T) -> QJSValue {
+        let obj_ptr = into_leaked_cpp_ptr(obj);
+        cpp!(unsafe [
+            self as "std::unique_ptr<QJSEngine> *",
+            obj_ptr as "QObject *"
+        ] -> QJSValue as "QJSValue" {
+            return (*self)->newQObject(obj_ptr);
+        })
+    }
+
+    /// Converts a QVariant to a JavaScript value (see QJSEngine::toScriptValue)
+    pub fn to_js_value(&self, value: QVariant) -> QJSValue {
+        cpp!(unsafe [self as "std::unique_ptr<QJSEngine> *", value as "QVariant"] -> QJSValue as "QJSValue" {
+            return (*self)->toScriptValue(value);
+        })
+    }
+
+    /// Runs the JavaScript garbage collector (see QJSEngine::collectGarbage)
+    pub fn collect_garbage(&mut self) {
+        cpp!(unsafe [self as "std::unique_ptr<QJSEngine> *"] {
+            (*self)->collectGarbage();
+        })
+    }
+
+    /// Returns a pointer to the C++ QJSEngine
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "std::unique_ptr<QJSEngine> *"] -> *mut c_void as "QJSEngine *" {
+            return self->get();
+        })
+    }
+}
+
+impl Default for JsEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
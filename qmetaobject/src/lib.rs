@@ -185,28 +185,47 @@ pub use crate::log::*;
 pub use connections::RustSignal;
 pub use connections::{connect, Signal, SignalInner};
 pub use future::*;
+pub use imageprovider::*;
 pub use itemmodel::*;
+pub use jsengine::*;
 pub use listmodel::*;
+pub use network::*;
 pub use qmetatype::*;
+pub use qobjectref::*;
 pub use qtdeclarative::*;
 #[cfg(qt_5_7)]
 pub use qtquickcontrols2::*;
 pub use qttypes::*;
+#[cfg(qt_5_8)]
+pub use renderer::*;
+pub use sortfilterproxymodel::*;
 pub use tablemodel::*;
 
 pub mod connections;
 pub mod future;
+#[cfg(feature = "hot_reload")]
+pub mod hotreload;
+pub mod imageprovider;
 pub mod itemmodel;
+pub mod jsengine;
 pub mod listmodel;
 pub mod log;
+pub mod network;
 pub mod qmetatype;
+pub mod qobjectref;
 pub mod qrc;
 pub mod qtcore;
 pub mod qtdeclarative;
 #[cfg(qt_5_7)]
 pub mod qtquickcontrols2;
+#[cfg(qt_5_8)]
+pub mod renderer;
 pub mod scenegraph;
+pub mod sortfilterproxymodel;
 pub mod tablemodel;
+#[cfg(feature = "qttest")]
+#[cfg(qt_5_11)]
+pub mod testing;
 #[cfg(feature = "webengine")]
 #[cfg(not(all(qt_6_0, not(qt_6_2))))]
 #[cfg(not(all(target_os = "windows", not(target_env = "msvc"))))]
@@ -519,6 +538,11 @@ impl<'pin, T: QObject + ?Sized + 'pin> QObjectPinned<'pin, T> {
         let x = self.0.borrow_mut();
         QObjectRefMut { old_value: x.get_cpp_object(), inner: x }
     }
+    /// Borrow the object mutably, or return None if it is already borrowed
+    pub fn try_borrow_mut(&self) -> Option<QObjectRefMut<'_, T>> {
+        let x = self.0.try_borrow_mut().ok()?;
+        Some(QObjectRefMut { old_value: x.get_cpp_object(), inner: x })
+    }
     pub fn as_ptr(&self) -> *mut T {
         self.0.as_ptr()
     }
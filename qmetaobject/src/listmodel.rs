@@ -17,8 +17,10 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
 use std::collections::HashMap;
+use std::future::Future;
 use std::iter::FromIterator;
 use std::ops::Index;
+use std::pin::Pin;
 
 use cpp::cpp;
 
@@ -50,6 +52,32 @@ pub trait QAbstractListModel: QObject {
     fn role_names(&self) -> HashMap<i32, QByteArray> {
         HashMap::new()
     }
+    /// Refer to the Qt documentation of QAbstractListModel::canFetchMore
+    fn can_fetch_more(&self, _parent: QModelIndex) -> bool {
+        false
+    }
+    /// Refer to the Qt documentation of QAbstractListModel::fetchMore
+    fn fetch_more(&mut self, _parent: QModelIndex) {}
+    /// Refer to the Qt documentation of QAbstractListModel::headerData
+    ///
+    /// The default implementation calls the one from QAbstractListModel.
+    fn header_data(&self, section: i32, orientation: Orientation, role: i32) -> QVariant {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QAbstractListModel*", section as "int", orientation as "Qt::Orientation", role as "int"] -> QVariant as "QVariant" {
+            return obj ? obj->baseHeaderData(section, orientation, role) : QVariant();
+        })
+    }
+    /// Refer to the Qt documentation of QAbstractListModel::flags
+    ///
+    /// The default implementation calls the one from QAbstractListModel.
+    fn flags(&self, index: QModelIndex) -> ItemFlags {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QAbstractListModel*", index as "QModelIndex"] -> ItemFlags as "Qt::ItemFlags" {
+            return obj ? obj->baseFlags(index) : Qt::ItemFlags();
+        })
+    }
+    /// Refer to the Qt documentation of QAbstractListModel::sort
+    fn sort(&mut self, _column: i32, _order: SortOrder) {}
 
     /// Refer to the Qt documentation of QAbstractListModel::beginInsertRows
     fn begin_insert_rows(&mut self, first: i32, last: i32) {
@@ -145,6 +173,14 @@ pub trait QAbstractListModel: QObject {
         }
     }
 
+    /// Refer to the Qt documentation of QAbstractListModel::headerDataChanged
+    fn header_data_changed(&mut self, orientation: Orientation, first: i32, last: i32) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QAbstractListModel*", orientation as "Qt::Orientation", first as "int", last as "int"] {
+            if (obj) obj->headerDataChanged(orientation, first, last);
+        })
+    }
+
     /// Returns a QModelIndex for the given row (in the first column)
     fn row_index(&self, i: i32) -> QModelIndex {
         let obj = self.get_cpp_object();
@@ -195,9 +231,35 @@ cpp! {{
             });
         }
 
-        //Qt::ItemFlags flags(const QModelIndex &index) const override;
+        Qt::ItemFlags flags(const QModelIndex &index) const override {
+            return rust!(Rust_QAbstractListModel_flags[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject",
+                    index : QModelIndex as "QModelIndex"] -> ItemFlags as "Qt::ItemFlags" {
+                rust_object.borrow().flags(index)
+            });
+        }
+
+        QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override {
+            return rust!(Rust_QAbstractListModel_headerData[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject",
+                    section : i32 as "int", orientation : Orientation as "Qt::Orientation", role : i32 as "int"]
+                    -> QVariant as "QVariant" {
+                rust_object.borrow().header_data(section, orientation, role)
+            });
+        }
 
-        //QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override;
+        void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override {
+            rust!(Rust_QAbstractListModel_sort[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject",
+                    column : i32 as "int", order : SortOrder as "Qt::SortOrder"] {
+                rust_object.borrow_mut().sort(column, order)
+            });
+        }
+
+        QVariant baseHeaderData(int section, Qt::Orientation orientation, int role) const {
+            return QAbstractListModel::headerData(section, orientation, role);
+        }
+
+        Qt::ItemFlags baseFlags(const QModelIndex &index) const {
+            return QAbstractListModel::flags(index);
+        }
 
         QHash<int, QByteArray> roleNames() const override {
             QHash<int, QByteArray> base = QAbstractListModel::roleNames();
@@ -210,6 +272,20 @@ cpp! {{
             return base;
         }
 
+        bool canFetchMore(const QModelIndex &parent) const override {
+            return rust!(Rust_QAbstractListModel_canFetchMore[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject",
+                    parent : QModelIndex as "QModelIndex"] -> bool as "bool" {
+                rust_object.borrow().can_fetch_more(parent)
+            });
+        }
+
+        void fetchMore(const QModelIndex &parent) override {
+            rust!(Rust_QAbstractListModel_fetchMore[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject",
+                    parent : QModelIndex as "QModelIndex"] {
+                rust_object.borrow_mut().fetch_more(parent)
+            });
+        }
+
         //QModelIndex index(int row, int column, const QModelIndex &parent) const override;
 
         //QModelIndex parent(const QModelIndex &child) const override;
@@ -319,3 +395,159 @@ where
         &self.values[index]
     }
 }
+
+type PageLoader<T> = Box<dyn FnMut(usize) -> Pin<Box<dyn Future<Output = Vec<T>>>>>;
+
+/// A QAbstractListModel which loads its items lazily, one page at the time.
+///
+/// The loader is called with the index of the page to load (starting at 0) when a view
+/// requests more data (see QAbstractItemModel::fetchMore). The returned future is run with
+/// `future::execute_async` and the items it resolves to are appended to the model.
+/// An empty page marks the end of the data.
+///
+/// Only one page is loaded at the time.
+#[derive(QObject)]
+// This is a bit weird because the rules are different as we are in the qmetaobject crate
+#[QMetaObjectCrate = "super"]
+pub struct PagedListModel<T: SimpleListItem + 'static> {
+    #[qt_base_class = "QAbstractListModel"]
+    base: QObjectCppWrapper,
+    values: Vec<T>,
+    loader: Option<PageLoader<T>>,
+    next_page: usize,
+    loading: bool,
+    finished: bool,
+    // Incremented by reset() so that the pages requested before are dropped
+    generation: usize,
+}
+
+impl<T: SimpleListItem + 'static> Default for PagedListModel<T> {
+    fn default() -> Self {
+        PagedListModel {
+            base: Default::default(),
+            values: Vec::new(),
+            loader: None,
+            next_page: 0,
+            loading: false,
+            finished: true,
+            generation: 0,
+        }
+    }
+}
+
+impl<T> QAbstractListModel for PagedListModel<T>
+where
+    T: SimpleListItem + 'static,
+{
+    fn row_count(&self) -> i32 {
+        self.values.len() as i32
+    }
+    fn data(&self, index: QModelIndex, role: i32) -> QVariant {
+        let idx = index.row();
+        if idx >= 0 && (idx as usize) < self.values.len() {
+            self.values[idx as usize].get(role - USER_ROLE)
+        } else {
+            QVariant::default()
+        }
+    }
+    fn role_names(&self) -> HashMap<i32, QByteArray> {
+        T::names().iter().enumerate().map(|(i, x)| (i as i32 + USER_ROLE, x.clone())).collect()
+    }
+    fn can_fetch_more(&self, parent: QModelIndex) -> bool {
+        !parent.is_valid() && !self.finished
+    }
+    fn fetch_more(&mut self, parent: QModelIndex) {
+        if parent.is_valid() || self.finished || self.loading {
+            return;
+        }
+        let page = match self.loader.as_mut() {
+            Some(loader) => loader(self.next_page),
+            None => return,
+        };
+        self.loading = true;
+        let generation = self.generation;
+        let this = QPointer::from(&*self);
+        let mut task = Some(async move {
+            let items = page.await;
+            if let Some(this) = this.as_pinned() {
+                this.borrow_mut().append_page(generation, items);
+            }
+        });
+        // Start the task from the event loop: fetchMore is called while the model is borrowed,
+        // and the page might already be ready when first polled.
+        single_shot(std::time::Duration::from_millis(0), move || {
+            if let Some(task) = task.take() {
+                future::execute_async(task);
+            }
+        });
+    }
+}
+
+impl<T: SimpleListItem + 'static> PagedListModel<T> {
+    /// Creates an empty model which will call `loader` to get the pages.
+    pub fn new<F, Fut>(mut loader: F) -> Self
+    where
+        F: FnMut(usize) -> Fut + 'static,
+        Fut: Future<Output = Vec<T>> + 'static,
+    {
+        PagedListModel {
+            loader: Some(Box::new(move |page| Box::pin(loader(page)))),
+            finished: false,
+            ..Default::default()
+        }
+    }
+
+    fn append_page(&mut self, generation: usize, items: Vec<T>) {
+        if generation != self.generation {
+            // Requested before a reset
+            return;
+        }
+        self.loading = false;
+        if items.is_empty() {
+            self.finished = true;
+            return;
+        }
+        self.next_page += 1;
+        let first = self.values.len() as i32;
+        let last = first + items.len() as i32 - 1;
+        (self as &mut dyn QAbstractListModel).begin_insert_rows(first, last);
+        self.values.extend(items);
+        (self as &mut dyn QAbstractListModel).end_insert_rows();
+    }
+
+    /// Removes all the items and start loading again from the first page.
+    ///
+    /// A page that is currently loading is discarded when it arrives.
+    pub fn reset(&mut self) {
+        (self as &mut dyn QAbstractListModel).begin_reset_model();
+        self.values.clear();
+        self.next_page = 0;
+        self.loading = false;
+        self.generation = self.generation.wrapping_add(1);
+        self.finished = self.loader.is_none();
+        (self as &mut dyn QAbstractListModel).end_reset_model();
+    }
+    /// Returns true if a page is being loaded
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+    /// Returns true if the loader returned an empty page
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+    /// Returns an iterator over the items loaded so far
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+}
+
+impl<T> Index<usize> for PagedListModel<T>
+where
+    T: SimpleListItem,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.values[index]
+    }
+}
@@ -0,0 +1,341 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Hooks into the network requests and URL resolution of the QML engine.
+
+use cpp::{cpp, cpp_class};
+
+use super::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtQml/QQmlEngine>
+    #include <QtQml/QQmlAbstractUrlInterceptor>
+    #include <QtQml/QQmlNetworkAccessManagerFactory>
+    #include <QtNetwork/QNetworkAccessManager>
+    #include <QtNetwork/QNetworkDiskCache>
+    #include <QtNetwork/QNetworkReply>
+    #include "qmetaobject_rust.hpp"
+
+    struct RustUrlInterceptor : QObject, QQmlAbstractUrlInterceptor {
+        TraitObject interceptor;
+
+        RustUrlInterceptor(TraitObject interceptor, QObject *parent)
+            : QObject(parent), interceptor(interceptor) {}
+
+        ~RustUrlInterceptor() {
+            rust!(Rust_RustUrlInterceptor_drop [interceptor: *mut dyn QmlUrlInterceptor as "TraitObject"] {
+                drop(Box::from_raw(interceptor));
+            });
+        }
+
+        QUrl intercept(const QUrl &url, DataType data_type) override {
+            return rust!(Rust_RustUrlInterceptor_intercept [
+                interceptor: &dyn QmlUrlInterceptor as "TraitObject",
+                url: QUrl as "QUrl",
+                data_type: UrlDataType as "QQmlAbstractUrlInterceptor::DataType"
+            ] -> QUrl as "QUrl" {
+                interceptor.intercept(url, data_type)
+            });
+        }
+    };
+
+    // A finished reply with the content given by Rust
+    struct RustNetworkReply : QNetworkReply {
+        // Returned by Rust_RustNetworkAccessManager_serve
+        enum Status { NotHandled = 0, Served = 1, Failed = 2 };
+        QByteArray content;
+        qint64 offset = 0;
+
+        RustNetworkReply(QNetworkAccessManager::Operation op, const QNetworkRequest &request,
+                         const QByteArray &content, const QByteArray &contentType,
+                         int status, const QString &error, QObject *parent)
+            : QNetworkReply(parent), content(content)
+        {
+            setRequest(request);
+            setUrl(request.url());
+            setOperation(op);
+            open(QIODevice::ReadOnly | QIODevice::Unbuffered);
+            if (status == Failed) {
+                this->content.clear();
+                setError(QNetworkReply::ContentNotFoundError, error);
+            } else {
+                if (!contentType.isEmpty())
+                    setHeader(QNetworkRequest::ContentTypeHeader, contentType);
+                setHeader(QNetworkRequest::ContentLengthHeader, this->content.size());
+            }
+            setFinished(true);
+            // The signals must be emitted after the reply was returned to the caller
+            QMetaObject::invokeMethod(this, "metaDataChanged", Qt::QueuedConnection);
+            if (status != Failed)
+                QMetaObject::invokeMethod(this, "readyRead", Qt::QueuedConnection);
+            QMetaObject::invokeMethod(this, "finished", Qt::QueuedConnection);
+        }
+
+        void abort() override {}
+        bool isSequential() const override { return true; }
+
+        qint64 bytesAvailable() const override {
+            return content.size() - offset + QIODevice::bytesAvailable();
+        }
+
+        qint64 readData(char *data, qint64 maxSize) override {
+            if (offset >= content.size())
+                return -1;
+            qint64 size = qMin(maxSize, content.size() - offset);
+            memcpy(data, content.constData() + offset, size);
+            offset += size;
+            return size;
+        }
+    };
+
+    // Shared by the factory and all the network access managers it created
+    using SharedFactory = std::shared_ptr<TraitObject>;
+
+    struct RustNetworkAccessManager : QNetworkAccessManager {
+        SharedFactory factory;
+
+        RustNetworkAccessManager(SharedFactory factory, QObject *parent)
+            : QNetworkAccessManager(parent), factory(std::move(factory)) {}
+
+    protected:
+        QNetworkReply *createRequest(Operation op, const QNetworkRequest &originalRequest,
+                                     QIODevice *outgoingData) override {
+            QNetworkRequest request = originalRequest;
+            TraitObject factory = *this->factory;
+            QNetworkRequest *request_ptr = &request;
+            rust!(Rust_RustNetworkAccessManager_prepareRequest [
+                factory: &dyn QmlNetworkAccessManagerFactory as "TraitObject",
+                request_ptr: &mut QNetworkRequest as "QNetworkRequest *"
+            ] {
+                factory.prepare_request(request_ptr);
+            });
+            if (op == GetOperation) {
+                QByteArray content;
+                QByteArray content_type;
+                QString error;
+                QByteArray *content_ptr = &content;
+                QByteArray *content_type_ptr = &content_type;
+                QString *error_ptr = &error;
+                int status = rust!(Rust_RustNetworkAccessManager_serve [
+                    factory: &dyn QmlNetworkAccessManagerFactory as "TraitObject",
+                    request_ptr: &QNetworkRequest as "QNetworkRequest *",
+                    content_ptr: &mut QByteArray as "QByteArray *",
+                    content_type_ptr: &mut QByteArray as "QByteArray *",
+                    error_ptr: &mut QString as "QString *"
+                ] -> i32 as "int" {
+                    match factory.serve_request(request_ptr) {
+                        None => 0,
+                        Some(Ok(reply)) => {
+                            *content_ptr = reply.content;
+                            *content_type_ptr = reply.content_type;
+                            1
+                        }
+                        Some(Err(e)) => {
+                            *error_ptr = e;
+                            2
+                        }
+                    }
+                });
+                if (status != RustNetworkReply::NotHandled)
+                    return new RustNetworkReply(op, request, content, content_type, status, error, this);
+            }
+            return QNetworkAccessManager::createRequest(op, request, outgoingData);
+        }
+    };
+
+    struct RustNetworkAccessManagerFactory : QObject, QQmlNetworkAccessManagerFactory {
+        SharedFactory factory;
+        QString cacheDirectory;
+
+        RustNetworkAccessManagerFactory(TraitObject f, QObject *parent)
+            : QObject(parent)
+            , factory(new TraitObject(f), [](TraitObject *p) {
+                TraitObject f = *p;
+                rust!(Rust_RustNetworkAccessManagerFactory_drop [
+                    f: *mut dyn QmlNetworkAccessManagerFactory as "TraitObject"
+                ] {
+                    drop(Box::from_raw(f));
+                });
+                delete p;
+            })
+        {
+            TraitObject f2 = f;
+            cacheDirectory = rust!(Rust_RustNetworkAccessManagerFactory_cacheDirectory [
+                f2: &dyn QmlNetworkAccessManagerFactory as "TraitObject"
+            ] -> QString as "QString" {
+                f2.cache_directory().unwrap_or_default()
+            });
+        }
+
+        // Called from the QML loader threads
+        QNetworkAccessManager *create(QObject *parent) override {
+            auto manager = new RustNetworkAccessManager(factory, parent);
+            if (!cacheDirectory.isEmpty()) {
+                auto cache = new QNetworkDiskCache(manager);
+                cache->setCacheDirectory(cacheDirectory);
+                manager->setCache(cache);
+            }
+            return manager;
+        }
+    };
+}}
+
+/// See QQmlAbstractUrlInterceptor::DataType
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UrlDataType {
+    QmlFile = 0,
+    JavaScriptFile = 1,
+    QmldirFile = 2,
+    /// Any other URL, such as the source of an Image
+    UrlString = 0x1000,
+}
+
+/// Rewrites the URLs resolved by the QML engine (see QQmlAbstractUrlInterceptor)
+///
+/// Register it with `QmlEngine::add_url_interceptor`. It is implemented for closures
+/// `Fn(QUrl, UrlDataType) -> QUrl`. It can be called from the threads loading QML files.
+pub trait QmlUrlInterceptor: Send + Sync {
+    /// Returns the URL to use instead of `url`, or `url` itself to leave it unchanged
+    fn intercept(&self, url: QUrl, data_type: UrlDataType) -> QUrl;
+}
+
+impl<F: Fn(QUrl, UrlDataType) -> QUrl + Send + Sync> QmlUrlInterceptor for F {
+    fn intercept(&self, url: QUrl, data_type: UrlDataType) -> QUrl {
+        self(url, data_type)
+    }
+}
+
+cpp_class!(
+    /// Wrapper around QNetworkRequest
+    #[derive(Clone)]
+    pub unsafe struct QNetworkRequest as "QNetworkRequest"
+);
+
+impl QNetworkRequest {
+    pub fn new(url: QUrl) -> QNetworkRequest {
+        cpp!(unsafe [url as "QUrl"] -> QNetworkRequest as "QNetworkRequest" {
+            return QNetworkRequest(url);
+        })
+    }
+
+    pub fn url(&self) -> QUrl {
+        cpp!(unsafe [self as "const QNetworkRequest *"] -> QUrl as "QUrl" {
+            return self->url();
+        })
+    }
+
+    pub fn set_url(&mut self, url: QUrl) {
+        cpp!(unsafe [self as "QNetworkRequest *", url as "QUrl"] {
+            self->setUrl(url);
+        })
+    }
+
+    pub fn has_raw_header(&self, name: QByteArray) -> bool {
+        cpp!(unsafe [self as "const QNetworkRequest *", name as "QByteArray"] -> bool as "bool" {
+            return self->hasRawHeader(name);
+        })
+    }
+
+    pub fn raw_header(&self, name: QByteArray) -> QByteArray {
+        cpp!(unsafe [self as "const QNetworkRequest *", name as "QByteArray"] -> QByteArray as "QByteArray" {
+            return self->rawHeader(name);
+        })
+    }
+
+    /// Refer to the Qt documentation of QNetworkRequest::setRawHeader
+    pub fn set_raw_header(&mut self, name: QByteArray, value: QByteArray) {
+        cpp!(unsafe [self as "QNetworkRequest *", name as "QByteArray", value as "QByteArray"] {
+            self->setRawHeader(name, value);
+        })
+    }
+}
+
+/// The content of a request served by `QmlNetworkAccessManagerFactory::serve_request`
+#[derive(Clone, Default)]
+pub struct NetworkReplyData {
+    pub content: QByteArray,
+    /// The Content-Type header. Can be empty.
+    pub content_type: QByteArray,
+}
+
+/// Customizes the network access of the QML engine
+/// (see QQmlNetworkAccessManagerFactory and QNetworkAccessManager::createRequest)
+///
+/// Register it with `QmlEngine::set_network_access_manager_factory`. The functions are called
+/// from the threads loading QML files and images, for the URLs which are not local files or
+/// resources.
+pub trait QmlNetworkAccessManagerFactory: Send + Sync {
+    /// Called before each request is sent. It can change the URL or add headers,
+    /// for example for authentication.
+    fn prepare_request(&self, _request: &mut QNetworkRequest) {}
+
+    /// Serves a GET request from Rust instead of the network, e.g. for a custom URL scheme
+    ///
+    /// Returns None to let the network access manager handle the request. An error makes
+    /// the request fail with QNetworkReply::ContentNotFoundError.
+    fn serve_request(
+        &self,
+        _request: &QNetworkRequest,
+    ) -> Option<Result<NetworkReplyData, QString>> {
+        None
+    }
+
+    /// A directory where the replies are cached with a QNetworkDiskCache, or None to not
+    /// cache them. This is called once when the factory is set.
+    fn cache_directory(&self) -> Option<QString> {
+        None
+    }
+}
+
+impl QmlEngine {
+    /// Adds a URL interceptor (see QQmlEngine::addUrlInterceptor)
+    ///
+    /// Before Qt 5.15, an engine can only have one interceptor, which is replaced.
+    pub fn add_url_interceptor<I: QmlUrlInterceptor + 'static>(&mut self, interceptor: I) {
+        let interceptor: *mut dyn QmlUrlInterceptor = Box::into_raw(Box::new(interceptor));
+        let engine = self.cpp_ptr();
+        cpp!(unsafe [engine as "QQmlEngine *", interceptor as "TraitObject"] {
+            // Owned by the engine, so it is deleted with it
+            auto i = new RustUrlInterceptor(interceptor, engine);
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 15, 0)
+                engine->addUrlInterceptor(i);
+            #else
+                delete dynamic_cast<RustUrlInterceptor *>(engine->urlInterceptor());
+                engine->setUrlInterceptor(i);
+            #endif
+        })
+    }
+
+    /// Sets the factory used to create the network access managers of the engine
+    /// (see QQmlEngine::setNetworkAccessManagerFactory)
+    ///
+    /// This must be called before loading any QML.
+    pub fn set_network_access_manager_factory<F: QmlNetworkAccessManagerFactory + 'static>(
+        &mut self,
+        factory: F,
+    ) {
+        let factory: *mut dyn QmlNetworkAccessManagerFactory = Box::into_raw(Box::new(factory));
+        let engine = self.cpp_ptr();
+        cpp!(unsafe [engine as "QQmlEngine *", factory as "TraitObject"] {
+            auto previous = engine->networkAccessManagerFactory();
+            engine->setNetworkAccessManagerFactory(new RustNetworkAccessManagerFactory(factory, engine));
+            delete dynamic_cast<RustNetworkAccessManagerFactory *>(previous);
+        })
+    }
+}
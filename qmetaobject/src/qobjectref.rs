@@ -0,0 +1,313 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! A handle to any QObject, including the objects created by QML.
+
+use std::marker::PhantomData;
+
+use cpp::cpp;
+
+use super::*;
+
+cpp! {{
+    #include <QtCore/QObject>
+    #include <QtCore/QPointer>
+    #include <QtCore/QMetaMethod>
+    #include "qmetaobject_rust.hpp"
+
+    // Receives a signal with any arguments and forwards them as QVariant to a Rust closure.
+    // Same principle as QSignalSpy: the connection uses a method index past the end of the
+    // meta object, so the call ends up in qt_metacall.
+    class RustSignalRelay : public QObject {
+        TraitObject slot;
+        QMetaMethod signal;
+    public:
+        // Number of calls to the closure in progress
+        int calls = 0;
+
+        RustSignalRelay(QObject *sender, const QMetaMethod &signal, TraitObject slot)
+            : QObject(sender), slot(slot), signal(signal) {}
+
+        ~RustSignalRelay() {
+            rust!(RustSignalRelay_destruct [slot: *mut dyn FnMut(&[QVariant]) as "TraitObject"] {
+                let _ = unsafe { Box::from_raw(slot) };
+            });
+        }
+
+        int qt_metacall(QMetaObject::Call call, int id, void **a) override {
+            id = QObject::qt_metacall(call, id, a);
+            if (id < 0 || call != QMetaObject::InvokeMetaMethod)
+                return id;
+            if (id == 0) {
+                QVariantList args;
+                for (int i = 0; i < signal.parameterCount(); ++i) {
+                    int type = signal.parameterType(i);
+                    if (type == QMetaType::QVariant) {
+                        args.append(*reinterpret_cast<QVariant *>(a[i + 1]));
+                    } else {
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+                        args.append(QVariant(signal.parameterMetaType(i), a[i + 1]));
+#else
+                        args.append(QVariant(type, a[i + 1]));
+#endif
+                    }
+                }
+                ++calls;
+                rust!(RustSignalRelay_call [
+                    slot: *mut dyn FnMut(&[QVariant]) as "TraitObject",
+                    args: QVariantList as "QVariantList"
+                ] {
+                    let args: Vec<QVariant> = args.into_iter().cloned().collect();
+                    let slot = unsafe { &mut *slot };
+                    slot(&args);
+                });
+                --calls;
+            }
+            return id - 1;
+        }
+    };
+
+    // Appends the objects to a Rust `Vec<QObjectRef>`
+    void qobjectsToRust(const QList<QObject *> &objects, void *vec) {
+        for (QObject *o : objects) {
+            QPointer<QObject> ptr = o;
+            rust!(Rust_qobjectsToRust[vec: &mut Vec<QObjectRef> as "void *", ptr: QPointerImpl as "QPointer<QObject>"] {
+                vec.push(QObjectRef(ptr, PhantomData));
+            });
+        }
+    }
+}}
+
+/// A handle to a QObject of any type, for example an object created by QML.
+///
+/// The handle is backed by a QPointer, so it becomes null when the object is destroyed, and all
+/// the functions can be called safely on a null handle.
+/// It does not own the object.
+#[derive(Clone, Default)]
+pub struct QObjectRef(QPointerImpl, PhantomData<*mut c_void>);
+
+impl QObjectRef {
+    /// Creates a handle from a pointer to a C++ `QObject`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be null or point to a valid QObject.
+    pub unsafe fn from_cpp_ptr(ptr: *mut c_void) -> QObjectRef {
+        QObjectRef(
+            cpp!([ptr as "QObject *"] -> QPointerImpl as "QPointer<QObject>" {
+                return ptr;
+            }),
+            PhantomData,
+        )
+    }
+
+    /// Creates a handle to a QObject implemented in Rust. This creates the C++ object if needed.
+    pub fn from_object<T: QObject>(obj: QObjectPinned<T>) -> QObjectRef {
+        unsafe { QObjectRef::from_cpp_ptr(obj.get_or_create_cpp_object()) }
+    }
+
+    /// Returns a pointer to the C++ `QObject` (null if it was destroyed)
+    pub fn as_cpp_ptr(&self) -> *mut c_void {
+        let x = &self.0;
+        cpp!(unsafe [x as "QPointer<QObject> *"] -> *mut c_void as "QObject *" {
+            return x->data();
+        })
+    }
+
+    /// Returns true if the object was destroyed, or if the handle was default constructed
+    pub fn is_null(&self) -> bool {
+        self.as_cpp_ptr().is_null()
+    }
+
+    /// Refer to the Qt documentation of QObject::objectName
+    pub fn object_name(&self) -> QString {
+        let obj = self.as_cpp_ptr();
+        cpp!(unsafe [obj as "QObject *"] -> QString as "QString" {
+            return obj ? obj->objectName() : QString();
+        })
+    }
+
+    /// Returns the class name from the QMetaObject of the object (see QMetaObject::className)
+    pub fn class_name(&self) -> QByteArray {
+        let obj = self.as_cpp_ptr();
+        cpp!(unsafe [obj as "QObject *"] -> QByteArray as "QByteArray" {
+            return obj ? QByteArray(obj->metaObject()->className()) : QByteArray();
+        })
+    }
+
+    /// Refer to the Qt documentation of QObject::property
+    ///
+    /// Returns an invalid QVariant if there is no such property.
+    pub fn property(&self, name: QByteArray) -> QVariant {
+        let obj = self.as_cpp_ptr();
+        cpp!(unsafe [obj as "QObject *", name as "QByteArray"] -> QVariant as "QVariant" {
+            return obj ? obj->property(name.constData()) : QVariant();
+        })
+    }
+
+    /// Refer to the Qt documentation of QObject::setProperty
+    ///
+    /// Returns false if the object does not have a property with this name (in which case Qt
+    /// creates a dynamic property), if the value has the wrong type, or if the object was
+    /// destroyed.
+    pub fn set_property(&self, name: QByteArray, value: QVariant) -> bool {
+        let obj = self.as_cpp_ptr();
+        cpp!(unsafe [obj as "QObject *", name as "QByteArray", value as "QVariant"] -> bool as "bool" {
+            return obj ? obj->setProperty(name.constData(), value) : false;
+        })
+    }
+
+    /// Refer to the Qt documentation of QObject::parent
+    pub fn parent(&self) -> QObjectRef {
+        let obj = self.as_cpp_ptr();
+        unsafe {
+            QObjectRef::from_cpp_ptr(cpp!([obj as "QObject *"] -> *mut c_void as "QObject *" {
+                return obj ? obj->parent() : nullptr;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QObject::setParent
+    ///
+    /// The object will be destroyed with its parent. Passing a null handle removes the parent.
+    pub fn set_parent(&self, parent: &QObjectRef) {
+        let obj = self.as_cpp_ptr();
+        let parent = parent.as_cpp_ptr();
+        cpp!(unsafe [obj as "QObject *", parent as "QObject *"] {
+            if (obj) obj->setParent(parent);
+        })
+    }
+
+    /// Refer to the Qt documentation of QObject::deleteLater
+    ///
+    /// The object is destroyed when control returns to the event loop.
+    pub fn delete_later(&self) {
+        let obj = self.as_cpp_ptr();
+        cpp!(unsafe [obj as "QObject *"] {
+            if (obj) obj->deleteLater();
+        })
+    }
+
+    /// Returns the first child (recursively) with the given object name, or None
+    /// (see QObject::findChild)
+    pub fn find_child(&self, name: QString) -> Option<QObjectRef> {
+        let obj = self.as_cpp_ptr();
+        let child = cpp!(unsafe [obj as "QObject *", name as "QString"] -> *mut c_void as "QObject *" {
+            return obj ? obj->findChild<QObject *>(name) : nullptr;
+        });
+        if child.is_null() {
+            None
+        } else {
+            Some(unsafe { QObjectRef::from_cpp_ptr(child) })
+        }
+    }
+
+    /// Returns all the children (recursively) with the given object name
+    /// (see QObject::findChildren)
+    pub fn find_children(&self, name: QString) -> Vec<QObjectRef> {
+        let obj = self.as_cpp_ptr();
+        let mut result = Vec::new();
+        let result_ptr = &mut result;
+        cpp!(unsafe [obj as "QObject *", name as "QString", result_ptr as "void *"] {
+            if (obj) qobjectsToRust(obj->findChildren<QObject *>(name), result_ptr);
+        });
+        result
+    }
+
+    /// Connects a signal of the object to a closure.
+    ///
+    /// The signal is given by name (e.g. `"clicked"`, the first signal with that name is used)
+    /// or by signature (e.g. `"valueChanged(int)"`), so signals declared in QML and property
+    /// notify signals can be used. The closure receives the arguments of the signal converted
+    /// to QVariant.
+    ///
+    /// Returns None if the object does not have such signal or if it was destroyed.
+    /// The closure is dropped when the object is destroyed, or when the connection is
+    /// disconnected with `SignalConnection::disconnect`.
+    pub fn connect_signal<F: FnMut(&[QVariant]) + 'static>(
+        &self,
+        signal: QByteArray,
+        slot: F,
+    ) -> Option<SignalConnection> {
+        let obj = self.as_cpp_ptr();
+        let signal_index = cpp!(unsafe [obj as "QObject *", signal as "QByteArray"] -> i32 as "int" {
+            if (!obj)
+                return -1;
+            const QMetaObject *mo = obj->metaObject();
+            if (signal.contains('('))
+                return mo->indexOfSignal(QMetaObject::normalizedSignature(signal.constData()));
+            for (int i = 0; i < mo->methodCount(); ++i) {
+                QMetaMethod m = mo->method(i);
+                if (m.methodType() == QMetaMethod::Signal && m.name() == signal)
+                    return i;
+            }
+            return -1;
+        });
+        if signal_index < 0 {
+            return None;
+        }
+        let slot: Box<dyn FnMut(&[QVariant])> = Box::new(slot);
+        let slot_raw = Box::into_raw(slot);
+        let relay = cpp!(unsafe [
+            obj as "QObject *",
+            signal_index as "int",
+            slot_raw as "TraitObject"
+        ] -> *mut c_void as "QObject *" {
+            auto relay = new RustSignalRelay(obj, obj->metaObject()->method(signal_index), slot_raw);
+            QMetaObject::connect(obj, signal_index, relay, relay->metaObject()->methodCount(),
+                                 Qt::DirectConnection);
+            return relay;
+        });
+        Some(SignalConnection { relay: unsafe { QObjectRef::from_cpp_ptr(relay) } })
+    }
+}
+
+/// A connection made with `QObjectRef::connect_signal`
+///
+/// Dropping the handle does not disconnect the signal.
+pub struct SignalConnection {
+    relay: QObjectRef,
+}
+
+impl SignalConnection {
+    /// Disconnects the signal and drops the closure. If the closure is running, it is dropped
+    /// when the control returns to the event loop.
+    ///
+    /// Does nothing if the connection was already disconnected or if the object was destroyed.
+    pub fn disconnect(&mut self) {
+        let relay = self.relay.as_cpp_ptr();
+        cpp!(unsafe [relay as "RustSignalRelay *"] {
+            if (!relay)
+                return;
+            QObject::disconnect(relay->parent(), nullptr, relay, nullptr);
+            if (relay->calls > 0)
+                relay->deleteLater();
+            else
+                delete relay;
+        })
+    }
+}
+
+impl std::fmt::Debug for QObjectRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_null() {
+            write!(f, "QObjectRef(null)")
+        } else {
+            write!(f, "QObjectRef({}, {:?})", self.class_name(), self.object_name())
+        }
+    }
+}
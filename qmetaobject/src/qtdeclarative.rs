@@ -26,10 +26,15 @@ static HAS_ENGINE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool
 
 cpp! {{
     #include <memory>
+    #include <vector>
     #include <QtQuick/QtQuick>
     #include <QtCore/QDebug>
     #include <QtWidgets/QApplication>
     #include <QtQml/QQmlComponent>
+    #include <QtQml/QQmlContext>
+    #include <QtQml/QQmlIncubator>
+    #include <QtQml/QJSValueIterator>
+    #include <QtCore/QBasicTimer>
 
     struct SingleApplicationGuard {
         SingleApplicationGuard() {
@@ -51,10 +56,53 @@ cpp! {{
         std::unique_ptr<QQmlApplicationEngine> engine;
         std::unique_ptr<QQuickView> view;
 
-        QmlEngineHolder(int &argc, char **argv)
-            : app(new QApplication(argc, argv))
+        QmlEngineHolder(int &argc, char **argv, bool offscreen = false)
+            : app(createApplication(argc, argv, offscreen))
             , engine(new QQmlApplicationEngine())
         {}
+
+        // The platform and the scene graph backend must be chosen before the application exists.
+        // With `offscreen`, the offscreen platform is used unless QT_QPA_PLATFORM is set, and
+        // the scene graph uses the software backend. The backend is global to the process, so
+        // it stays selected for the applications created later.
+        static QApplication *createApplication(int &argc, char **argv, bool offscreen) {
+            if (!offscreen)
+                return new QApplication(argc, argv);
+    #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            QQuickWindow::setGraphicsApi(QSGRendererInterface::Software);
+    #elif QT_VERSION >= QT_VERSION_CHECK(5, 8, 0)
+            QQuickWindow::setSceneGraphBackend(QSGRendererInterface::Software);
+    #endif
+            if (qEnvironmentVariableIsSet("QT_QPA_PLATFORM"))
+                return new QApplication(argc, argv);
+            // The application keeps a reference to them, and removes the arguments it handled.
+            // There is only one application at the time, so they can be rebuilt for each one.
+            static int offscreenArgc;
+            static std::vector<char *> offscreenArgv;
+            static char platformArg[] = "-platform";
+            static char offscreenArg[] = "offscreen";
+            offscreenArgv.assign(argv, argv + argc);
+            offscreenArgv.push_back(platformArg);
+            offscreenArgv.push_back(offscreenArg);
+            offscreenArgc = int(offscreenArgv.size());
+            offscreenArgv.push_back(nullptr);
+            return new QApplication(offscreenArgc, offscreenArgv.data());
+        }
+    };
+
+    struct QmlContextHolder {
+        // Shared between the clones. Deletes the context if it is owned and still alive.
+        std::shared_ptr<QPointer<QQmlContext>> context;
+
+        QmlContextHolder(QQmlContext *c, bool owned)
+            : context(new QPointer<QQmlContext>(c), [owned](QPointer<QQmlContext> *p) {
+                if (owned)
+                    delete p->data();
+                delete p;
+            })
+        {}
+
+        QQmlContext *get() const { return context ? context->data() : nullptr; }
     };
 
 // Equivalent with QMetaObject::inherits(), for 5.6 and lower.
@@ -76,8 +124,92 @@ cpp! {{
 #else
     using CreatorFunction = void (*)(void *, void*);
 #endif
+
+    // Defined in qobjectref.rs
+    void qobjectsToRust(const QList<QObject *> &objects, void *vec);
+
+    static QJSValueList qjsValueList(const QJSValue *args, size_t size) {
+        QJSValueList list;
+        list.reserve(int(size));
+        for (size_t i = 0; i < size; ++i)
+            list.append(args[i]);
+        return list;
+    }
+
+    // Appends the errors to a Rust `Vec<QmlError>`
+    void qmlErrorsToRust(const QList<QQmlError> &errors, void *vec) {
+        for (const QQmlError &e : errors) {
+            QString url = e.url().toString();
+            int line = e.line();
+            int column = e.column();
+            QString description = e.description();
+#if QT_VERSION >= QT_VERSION_CHECK(5, 9, 0)
+            QtMsgType msg_type = e.messageType();
+#else
+            QtMsgType msg_type = QtWarningMsg;
+#endif
+            rust!(Rust_qmlErrorsToRust[
+                vec: &mut Vec<QmlError> as "void *",
+                url: QString as "QString",
+                line: i32 as "int",
+                column: i32 as "int",
+                description: QString as "QString",
+                msg_type: QtMsgType as "QtMsgType"
+            ] {
+                vec.push(QmlError { url, line, column, description, message_type: msg_type });
+            });
+        }
+    }
+
+    // Calls the load function, and collects the warnings emitted by the engine meanwhile.
+    // Returns true if a root object was created without any warnings.
+    template<typename F>
+    bool qmlLoadCollectingWarnings(QQmlApplicationEngine *engine, void *vec, F load) {
+        QList<QQmlError> warnings;
+        auto connection = QObject::connect(engine, &QQmlEngine::warnings, [&](const QList<QQmlError> &w) {
+            warnings += w;
+        });
+        int count = engine->rootObjects().size();
+        load();
+        QObject::disconnect(connection);
+        qmlErrorsToRust(warnings, vec);
+        return warnings.isEmpty() && engine->rootObjects().size() > count;
+    }
 }}
 
+/// An error or a warning reported by the QML engine (see QQmlError)
+#[derive(Clone, Debug, PartialEq)]
+pub struct QmlError {
+    /// The url of the file containing the error, or an empty string
+    pub url: QString,
+    /// The line (starting from 1), or 0 if unknown
+    pub line: i32,
+    /// The column (starting from 1), or 0 if unknown
+    pub column: i32,
+    /// The error description
+    pub description: QString,
+    /// The severity of the message (always QtWarningMsg before Qt 5.9)
+    pub message_type: QtMsgType,
+}
+
+impl std::fmt::Display for QmlError {
+    /// Same format as QQmlError::toString
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.url.is_empty() {
+            write!(f, "<Unknown File>")?;
+        } else {
+            write!(f, "{}", self.url)?;
+        }
+        if self.line > 0 {
+            write!(f, ":{}", self.line)?;
+            if self.column > 0 {
+                write!(f, ":{}", self.column)?;
+            }
+        }
+        write!(f, ": {}", self.description)
+    }
+}
+
 cpp_class!(
     /// Wrap a Qt Application and a QmlEngine
     ///
@@ -88,6 +220,17 @@ cpp_class!(
 impl QmlEngine {
     /// Create a new QmlEngine
     pub fn new() -> QmlEngine {
+        QmlEngine::create(false)
+    }
+
+    /// Create a QmlEngine whose application uses the offscreen platform, unless the
+    /// `QT_QPA_PLATFORM` environment variable is set, and the software scene graph backend
+    #[cfg(qt_5_8)]
+    pub(crate) fn new_offscreen() -> QmlEngine {
+        QmlEngine::create(true)
+    }
+
+    fn create(offscreen: bool) -> QmlEngine {
         let mut arguments: Vec<*mut c_char> = std::env::args()
             .map(|arg| CString::new(arg.into_bytes()).expect("argument contains invalid c-string!"))
             .map(|arg| arg.into_raw())
@@ -97,7 +240,8 @@ impl QmlEngine {
 
         let result = cpp!(unsafe [
             argc as "int",
-            argv as "char **"
+            argv as "char **",
+            offscreen as "bool"
         ] -> QmlEngine as "QmlEngineHolder" {
             // Static variables when used inside function are initialized only once
             static int _argc  = argc;
@@ -113,7 +257,7 @@ impl QmlEngine {
                     strcpy(_argv[i], argv[i]);
                 }
             }
-            return QmlEngineHolder(_argc, _argv);
+            return QmlEngineHolder(_argc, _argv, offscreen);
         });
 
         // run destructor
@@ -152,6 +296,76 @@ impl QmlEngine {
         })
     }
 
+    /// Same as `load_file`, but returns the errors and warnings reported while loading.
+    ///
+    /// Returns an error if the root object could not be created, or if the engine reported
+    /// warnings (see QQmlEngine::warnings) while loading it.
+    /// Note that a remote file is loaded asynchronously, so the errors are not reported here.
+    pub fn try_load_file(&mut self, path: QString) -> Result<(), Vec<QmlError>> {
+        let mut errors = Vec::new();
+        let errors_ptr = &mut errors;
+        let ok = cpp!(unsafe [
+            self as "QmlEngineHolder *",
+            path as "QString",
+            errors_ptr as "void *"
+        ] -> bool as "bool" {
+            return qmlLoadCollectingWarnings(self->engine.get(), errors_ptr, [&] { self->engine->load(path); });
+        });
+        if ok {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Same as `load_url`, but returns the errors and warnings reported while loading.
+    ///
+    /// See `try_load_file`.
+    pub fn try_load_url(&mut self, url: QUrl) -> Result<(), Vec<QmlError>> {
+        let mut errors = Vec::new();
+        let errors_ptr = &mut errors;
+        let ok = cpp!(unsafe [
+            self as "QmlEngineHolder *",
+            url as "QUrl",
+            errors_ptr as "void *"
+        ] -> bool as "bool" {
+            return qmlLoadCollectingWarnings(self->engine.get(), errors_ptr, [&] { self->engine->load(url); });
+        });
+        if ok {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Same as `load_data`, but returns the errors and warnings reported while loading.
+    ///
+    /// See `try_load_file`.
+    pub fn try_load_data(&mut self, data: QByteArray) -> Result<(), Vec<QmlError>> {
+        self.try_load_data_as(data, QUrl::default())
+    }
+
+    /// Same as `load_data_as`, but returns the errors and warnings reported while loading.
+    ///
+    /// See `try_load_file`.
+    pub fn try_load_data_as(&mut self, data: QByteArray, url: QUrl) -> Result<(), Vec<QmlError>> {
+        let mut errors = Vec::new();
+        let errors_ptr = &mut errors;
+        let ok = cpp!(unsafe [
+            self as "QmlEngineHolder *",
+            data as "QByteArray",
+            url as "QUrl",
+            errors_ptr as "void *"
+        ] -> bool as "bool" {
+            return qmlLoadCollectingWarnings(self->engine.get(), errors_ptr, [&] { self->engine->loadData(data, url); });
+        });
+        if ok {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Launches the application
     pub fn exec(&self) {
         cpp!(unsafe [self as "QmlEngineHolder *"] {
@@ -292,6 +506,37 @@ impl QmlEngine {
         })
     }
 
+    /// Creates a new JavaScript object (see QJSEngine::newObject)
+    pub fn new_object(&self) -> QJSValue {
+        cpp!(unsafe [self as "QmlEngineHolder *"] -> QJSValue as "QJSValue" {
+            return self->engine->newObject();
+        })
+    }
+
+    /// Creates a new JavaScript array of the given length (see QJSEngine::newArray)
+    pub fn new_array(&self, length: u32) -> QJSValue {
+        cpp!(unsafe [self as "QmlEngineHolder *", length as "uint"] -> QJSValue as "QJSValue" {
+            return self->engine->newArray(length);
+        })
+    }
+
+    /// Converts a QVariant to a JavaScript value owned by this engine
+    /// (see QJSEngine::toScriptValue)
+    ///
+    /// Lists and maps are converted to arrays and objects.
+    pub fn to_js_value(&self, value: QVariant) -> QJSValue {
+        cpp!(unsafe [self as "QmlEngineHolder *", value as "QVariant"] -> QJSValue as "QJSValue" {
+            return self->engine->toScriptValue(value);
+        })
+    }
+
+    /// Returns the global object of the engine (see QJSEngine::globalObject)
+    pub fn global_object(&self) -> QJSValue {
+        cpp!(unsafe [self as "QmlEngineHolder *"] -> QJSValue as "QJSValue" {
+            return self->engine->globalObject();
+        })
+    }
+
     /// Adds an import path for this QML engine (calls QQmlEngine::addImportPath)
     pub fn add_import_path(&mut self, path: QString) {
         cpp!(unsafe [self as "QmlEngineHolder *", path as "QString"] {
@@ -299,6 +544,43 @@ impl QmlEngine {
         })
     }
 
+    /// Returns the root context of the engine (see QQmlEngine::rootContext)
+    pub fn root_context(&self) -> QmlContext {
+        cpp!(unsafe [self as "QmlEngineHolder *"] -> QmlContext as "QmlContextHolder" {
+            return QmlContextHolder(self->engine->rootContext(), false);
+        })
+    }
+
+    /// Returns the objects created by the `load_*` functions
+    /// (see QQmlApplicationEngine::rootObjects)
+    pub fn root_objects(&self) -> Vec<QObjectRef> {
+        let mut result = Vec::new();
+        let result_ptr = &mut result;
+        cpp!(unsafe [self as "QmlEngineHolder *", result_ptr as "void *"] {
+            qobjectsToRust(self->engine->rootObjects(), result_ptr);
+        });
+        result
+    }
+
+    /// Returns the first root object, or child (recursively) of a root object, with the given
+    /// object name.
+    pub fn find_child(&self, name: QString) -> Option<QObjectRef> {
+        self.find_children(name).into_iter().next()
+    }
+
+    /// Returns all the root objects, and children (recursively) of the root objects, with the
+    /// given object name.
+    pub fn find_children(&self, name: QString) -> Vec<QObjectRef> {
+        let mut result = Vec::new();
+        for root in self.root_objects() {
+            if root.object_name() == name {
+                result.push(root.clone());
+            }
+            result.extend(root.find_children(name.clone()));
+        }
+        result
+    }
+
     /// Returns a pointer to the C++ object. The pointer is of the type `QQmlEngine *` in C++.
     pub fn cpp_ptr(&self) -> *mut c_void {
         cpp!(unsafe [self as "QmlEngineHolder *"] -> *mut c_void as "QQmlEngine *" {
@@ -369,14 +651,150 @@ pub enum ComponentStatus {
     Error,
 }
 
+cpp_class!(
+    /// Wrapper for QQmlContext
+    ///
+    /// The context created with `new_child` is destroyed when the last clone of this handle is
+    /// dropped, so it must be kept alive as long as the objects created in it are used.
+    /// The root context is owned by the engine.
+    #[derive(Clone)]
+    pub unsafe struct QmlContext as "QmlContextHolder"
+);
+
+impl QmlContext {
+    /// Creates a new context, child of `parent` (see QQmlContext::QQmlContext(QQmlContext *parent))
+    pub fn new_child(parent: &QmlContext) -> QmlContext {
+        cpp!(unsafe [parent as "QmlContextHolder *"] -> QmlContext as "QmlContextHolder" {
+            return QmlContextHolder(new QQmlContext(parent->get()), true);
+        })
+    }
+
+    /// Returns a pointer to the underlying QQmlContext, or null if it was destroyed
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QmlContextHolder *"] -> *mut c_void as "QQmlContext *" {
+            return self->get();
+        })
+    }
+
+    /// Returns false if the context was destroyed, or if its engine was destroyed
+    /// (see QQmlContext::isValid)
+    pub fn is_valid(&self) -> bool {
+        cpp!(unsafe [self as "QmlContextHolder *"] -> bool as "bool" {
+            auto c = self->get();
+            return c && c->isValid();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlContext::setContextProperty
+    pub fn set_context_property(&mut self, name: QString, value: QVariant) {
+        cpp!(unsafe [self as "QmlContextHolder *", name as "QString", value as "QVariant"] {
+            if (auto c = self->get())
+                c->setContextProperty(name, value);
+        })
+    }
+
+    /// Sets an object as a property for this context (see QQmlContext::setContextProperty)
+    ///
+    /// This will create the C++ object if needed.
+    pub fn set_context_object_property<T: QObject + Sized>(
+        &mut self,
+        name: QString,
+        obj: QObjectPinned<T>,
+    ) {
+        let obj_ptr = obj.get_or_create_cpp_object();
+        cpp!(unsafe [self as "QmlContextHolder *", name as "QString", obj_ptr as "QObject *"] {
+            if (auto c = self->get())
+                c->setContextProperty(name, obj_ptr);
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlContext::setContextObject
+    ///
+    /// This will create the C++ object if needed.
+    pub fn set_context_object<T: QObject + Sized>(&mut self, obj: QObjectPinned<T>) {
+        let obj_ptr = obj.get_or_create_cpp_object();
+        cpp!(unsafe [self as "QmlContextHolder *", obj_ptr as "QObject *"] {
+            if (auto c = self->get())
+                c->setContextObject(obj_ptr);
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlContext::contextProperty
+    pub fn context_property(&self, name: QString) -> QVariant {
+        cpp!(unsafe [self as "QmlContextHolder *", name as "QString"] -> QVariant as "QVariant" {
+            auto c = self->get();
+            return c ? c->contextProperty(name) : QVariant();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlContext::baseUrl
+    pub fn base_url(&self) -> QUrl {
+        cpp!(unsafe [self as "QmlContextHolder *"] -> QUrl as "QUrl" {
+            auto c = self->get();
+            return c ? c->baseUrl() : QUrl();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlContext::setBaseUrl
+    pub fn set_base_url(&mut self, url: QUrl) {
+        cpp!(unsafe [self as "QmlContextHolder *", url as "QUrl"] {
+            if (auto c = self->get())
+                c->setBaseUrl(url);
+        })
+    }
+}
+
 cpp! {{
     struct QQmlComponentHolder {
         std::unique_ptr<QQmlComponent> component;
+        QQmlEngine *engine;
 
         QQmlComponentHolder(QQmlEngine *e)
             : component(new QQmlComponent(e))
+            , engine(e)
         {}
     };
+
+    // Drives the incubation from the event loop, for engines without an incubation controller
+    // (QQuickWindow installs one, but not QQmlApplicationEngine)
+    struct RustIncubationController : QObject, QQmlIncubationController {
+        QBasicTimer timer;
+
+        explicit RustIncubationController(QObject *parent) : QObject(parent) {}
+
+        void incubatingObjectCountChanged(int count) override {
+            if (count > 0 && !timer.isActive())
+                timer.start(0, this);
+            else if (count == 0)
+                timer.stop();
+        }
+
+        void timerEvent(QTimerEvent *e) override {
+            if (e->timerId() == timer.timerId())
+                incubateFor(5);
+            else
+                QObject::timerEvent(e);
+        }
+    };
+
+    struct RustIncubator : QQmlIncubator {
+        // A pointer to the IncubatorState owned by the QmlIncubator
+        const void *state;
+
+        RustIncubator(QQmlIncubator::IncubationMode mode, const void *state)
+            : QQmlIncubator(mode), state(state) {}
+
+        void statusChanged(Status status) override {
+            if (status == Ready)
+                QQmlEngine::setObjectOwnership(object(), QQmlEngine::CppOwnership);
+            rust!(Rust_RustIncubator_statusChanged[
+                state: &IncubatorState as "const void *",
+                status: IncubatorStatus as "QQmlIncubator::Status"
+            ] {
+                state.status_changed(status);
+            });
+        }
+    };
 }}
 
 cpp_class!(
@@ -425,10 +843,143 @@ impl QmlComponent {
     }
 
     /// Performs QQmlComponent::create
-    pub fn create(&mut self) -> *mut c_void {
-        cpp!(unsafe [self as "QQmlComponentHolder *"] -> *mut c_void as "QObject *" {
+    ///
+    /// Returns the errors of the component if the object could not be created.
+    pub fn create(&mut self) -> Result<*mut c_void, Vec<QmlError>> {
+        let obj = cpp!(unsafe [self as "QQmlComponentHolder *"] -> *mut c_void as "QObject *" {
             return self->component->create();
-        })
+        });
+        if obj.is_null() {
+            Err(self.errors())
+        } else {
+            Ok(obj)
+        }
+    }
+
+    /// Performs QQmlComponent::create(QQmlContext *context)
+    ///
+    /// The object is created in the given context, which must outlive it.
+    /// Returns the errors of the component if the object could not be created.
+    pub fn create_in(&mut self, context: &QmlContext) -> Result<*mut c_void, Vec<QmlError>> {
+        let obj = cpp!(unsafe [
+            self as "QQmlComponentHolder *",
+            context as "QmlContextHolder *"
+        ] -> *mut c_void as "QObject *" {
+            auto c = context->get();
+            return c ? self->component->create(c) : nullptr;
+        });
+        if obj.is_null() {
+            Err(self.errors())
+        } else {
+            Ok(obj)
+        }
+    }
+
+    /// Creates an object with the given initial properties
+    /// (see QQmlComponent::createWithInitialProperties)
+    ///
+    /// The properties are set before the bindings are evaluated and before `Component.onCompleted`
+    /// is called. With Qt 5, QQmlComponent::beginCreate and QQmlComponent::completeCreate are
+    /// used instead.
+    /// Returns an error if the component has errors or if a property could not be set.
+    ///
+    /// The caller owns the returned object (its ownership is set to QQmlEngine::CppOwnership so
+    /// the JavaScript garbage collector never deletes it). Use `QObjectRef::delete_later` to
+    /// destroy it, or give it a parent with `QObjectRef::set_parent`.
+    pub fn create_with_initial_properties(
+        &mut self,
+        properties: &QVariantMap,
+    ) -> Result<QObjectRef, Vec<QmlError>> {
+        let mut errors = Vec::new();
+        let errors_ptr = &mut errors;
+        let obj = cpp!(unsafe [
+            self as "QQmlComponentHolder *",
+            properties as "const QVariantMap *",
+            errors_ptr as "void *"
+        ] -> *mut c_void as "QObject *" {
+            QQmlComponent *component = self->component.get();
+            QList<QQmlError> errors;
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            QObject *obj = component->createWithInitialProperties(*properties);
+            if (component->isError())
+                errors = component->errors();
+            #else
+            QQmlContext *context = component->creationContext();
+            if (!context)
+                context = self->engine->rootContext();
+            QObject *obj = component->beginCreate(context);
+            if (obj) {
+                for (auto it = properties->cbegin(); it != properties->cend(); ++it) {
+                    if (!QQmlProperty::write(obj, it.key(), it.value(), context)) {
+                        QQmlError error;
+                        error.setUrl(component->url());
+                        error.setDescription(QStringLiteral("Could not set initial property %1").arg(it.key()));
+                        errors.append(error);
+                    }
+                }
+                component->completeCreate();
+            }
+            errors += component->errors();
+            #endif
+            if (!obj || !errors.isEmpty()) {
+                delete obj;
+                qmlErrorsToRust(errors.isEmpty() ? component->errors() : errors, errors_ptr);
+                return nullptr;
+            }
+            QQmlEngine::setObjectOwnership(obj, QQmlEngine::CppOwnership);
+            return obj;
+        });
+        if obj.is_null() {
+            Err(errors)
+        } else {
+            Ok(unsafe { QObjectRef::from_cpp_ptr(obj) })
+        }
+    }
+
+    /// Creates an object asynchronously (see QQmlComponent::create(QQmlIncubator &incubator))
+    ///
+    /// The returned QmlIncubator is a future which resolves to the object once it is created,
+    /// see `future::execute_async`.
+    pub fn incubate(&mut self, mode: IncubationMode) -> QmlIncubator {
+        self.incubate_impl(std::ptr::null_mut(), mode)
+    }
+
+    /// Same as `incubate`, but the object is created in the given context, which must outlive it.
+    pub fn incubate_in(&mut self, context: &QmlContext, mode: IncubationMode) -> QmlIncubator {
+        self.incubate_impl(context.cpp_ptr(), mode)
+    }
+
+    fn incubate_impl(&mut self, context: *mut c_void, mode: IncubationMode) -> QmlIncubator {
+        let state = Box::new(IncubatorState::default());
+        let state_ptr: *const IncubatorState = &*state;
+        let incubator = cpp!(unsafe [
+            self as "QQmlComponentHolder *",
+            context as "QQmlContext *",
+            mode as "QQmlIncubator::IncubationMode",
+            state_ptr as "const void *"
+        ] -> *mut c_void as "RustIncubator *" {
+            if (!self->engine->incubationController())
+                self->engine->setIncubationController(new RustIncubationController(self->engine));
+            auto incubator = new RustIncubator(mode, state_ptr);
+            self->component->create(*incubator, context);
+            return incubator;
+        });
+        let mut result = QmlIncubator { incubator, state, component_errors: Vec::new() };
+        if result.status() == IncubatorStatus::Null {
+            // The component was not ready, so the incubation did not start
+            result.component_errors = self.errors();
+        }
+        result
+    }
+
+    /// Performs QQmlComponent::errors
+    pub fn errors(&self) -> Vec<QmlError> {
+        let mut errors = Vec::new();
+        let errors_ptr = &mut errors;
+        cpp!(unsafe [self as "QQmlComponentHolder *", errors_ptr as "void *"] {
+            qmlErrorsToRust(self->component->errors(), errors_ptr);
+        });
+        errors
     }
 
     /// Performs QQmlComponent::status
@@ -450,6 +1001,151 @@ impl QmlComponent {
     }
 }
 
+/// See QQmlIncubator::IncubationMode
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IncubationMode {
+    Asynchronous,
+    AsynchronousIfNested,
+    Synchronous,
+}
+
+/// See QQmlIncubator::Status
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IncubatorStatus {
+    Null,
+    Ready,
+    Loading,
+    Error,
+}
+
+#[derive(Default)]
+struct IncubatorState {
+    waker: RefCell<Option<std::task::Waker>>,
+    callback: RefCell<Option<Box<dyn FnMut(IncubatorStatus)>>>,
+}
+
+impl IncubatorState {
+    fn status_changed(&self, status: IncubatorStatus) {
+        // The callback is taken out while it runs, so it can call back into the incubator
+        let callback = self.callback.borrow_mut().take();
+        if let Some(mut callback) = callback {
+            callback(status);
+            let mut slot = self.callback.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(callback);
+            }
+        }
+        if status != IncubatorStatus::Loading {
+            if let Some(waker) = self.waker.borrow_mut().take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// Wrapper for QQmlIncubator, created by `QmlComponent::incubate`
+///
+/// This is a future which resolves to the created object, or to the errors if the creation
+/// failed. The caller owns the created object (see `QmlComponent::create_with_initial_properties`).
+/// Dropping the incubator before the object is ready cancels the creation.
+///
+/// The incubation is driven by the incubation controller of the engine. Only when the engine does
+/// not have one yet (e.g. when there is no window), a controller which incubates the objects from
+/// the Qt event loop is installed. An existing controller, like the one of a QQuickWindow, is
+/// never replaced. The installed controller stays on the engine, so a window created later does
+/// not install its own: set it with QQmlEngine::setIncubationController if needed.
+pub struct QmlIncubator {
+    incubator: *mut c_void,
+    // Boxed so its address does not change, the C++ incubator points to it
+    state: Box<IncubatorState>,
+    component_errors: Vec<QmlError>,
+}
+
+impl QmlIncubator {
+    /// Refer to the Qt documentation of QQmlIncubator::status
+    pub fn status(&self) -> IncubatorStatus {
+        let incubator = self.incubator;
+        cpp!(unsafe [incubator as "RustIncubator *"] -> IncubatorStatus as "QQmlIncubator::Status" {
+            return incubator->status();
+        })
+    }
+
+    /// Sets a callback called every time the status changes (see QQmlIncubator::statusChanged)
+    pub fn on_status_changed<F: FnMut(IncubatorStatus) + 'static>(&mut self, callback: F) {
+        *self.state.callback.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Refer to the Qt documentation of QQmlIncubator::forceCompletion
+    pub fn force_completion(&mut self) {
+        let incubator = self.incubator;
+        cpp!(unsafe [incubator as "RustIncubator *"] {
+            incubator->forceCompletion();
+        })
+    }
+
+    /// Returns the created object if the status is Ready (see QQmlIncubator::object)
+    pub fn object(&self) -> Option<QObjectRef> {
+        let incubator = self.incubator;
+        let obj = cpp!(unsafe [incubator as "RustIncubator *"] -> *mut c_void as "QObject *" {
+            return incubator->object();
+        });
+        if obj.is_null() {
+            None
+        } else {
+            Some(unsafe { QObjectRef::from_cpp_ptr(obj) })
+        }
+    }
+
+    /// Refer to the Qt documentation of QQmlIncubator::errors
+    ///
+    /// If the incubation could not start because the component is not ready, the errors of the
+    /// component are returned.
+    pub fn errors(&self) -> Vec<QmlError> {
+        let incubator = self.incubator;
+        let mut errors = Vec::new();
+        let errors_ptr = &mut errors;
+        cpp!(unsafe [incubator as "RustIncubator *", errors_ptr as "void *"] {
+            qmlErrorsToRust(incubator->errors(), errors_ptr);
+        });
+        if errors.is_empty() {
+            errors = self.component_errors.clone();
+        }
+        errors
+    }
+}
+
+impl std::future::Future for QmlIncubator {
+    type Output = Result<QObjectRef, Vec<QmlError>>;
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        ctx: &mut std::task::Context,
+    ) -> std::task::Poll<Self::Output> {
+        match self.status() {
+            IncubatorStatus::Loading => {
+                *self.state.waker.borrow_mut() = Some(ctx.waker().clone());
+                std::task::Poll::Pending
+            }
+            IncubatorStatus::Ready => {
+                std::task::Poll::Ready(self.object().ok_or_else(|| self.errors()))
+            }
+            IncubatorStatus::Null | IncubatorStatus::Error => {
+                std::task::Poll::Ready(Err(self.errors()))
+            }
+        }
+    }
+}
+
+impl Drop for QmlIncubator {
+    fn drop(&mut self) {
+        let incubator = self.incubator;
+        cpp!(unsafe [incubator as "RustIncubator *"] {
+            delete incubator;
+        })
+    }
+}
+
 /// Register the given type as a QML type
 ///
 /// Refer to the Qt documentation for qmlRegisterType.
@@ -805,22 +1501,224 @@ pub trait QQuickItem: QObject {
         false
     }
 
-    fn geometry_changed(&mut self, _new_geometry: QRectF, _old_geometry: QRectF) {}
+    /// Handle key press events. Returns true if the event was accepted.
+    ///
+    /// Only the item with the active focus receives the key events. Ignored events are
+    /// propagated to the parent item.
+    fn key_press_event(&mut self, _event: QKeyEvent) -> bool {
+        false
+    }
 
-    fn update_paint_node(&mut self, node: SGNode<ContainerNode>) -> SGNode<ContainerNode> {
-        node
+    /// Handle key release events. Returns true if the event was accepted.
+    fn key_release_event(&mut self, _event: QKeyEvent) -> bool {
+        false
     }
-}
 
-cpp! {{
-    #include <qmetaobject_rust.hpp>
-    #include <QtQuick/QQuickItem>
+    /// Handle the hover events sent when the mouse enters the item. Returns true if the event
+    /// was accepted.
+    ///
+    /// Hover events are only sent if the item accepts them (see `set_accept_hover_events`).
+    fn hover_enter_event(&mut self, _event: QHoverEvent) -> bool {
+        false
+    }
 
-    #if QT_VERSION < QT_VERSION_CHECK(6, 0, 0)
-        #define QT_QQUICKITEM_GEOMETRYCHANGE geometryChanged
-    #else
-        #define QT_QQUICKITEM_GEOMETRYCHANGE geometryChange
-    #endif
+    /// Handle the hover events sent when the mouse moves over the item. Returns true if the
+    /// event was accepted.
+    fn hover_move_event(&mut self, _event: QHoverEvent) -> bool {
+        false
+    }
+
+    /// Handle the hover events sent when the mouse leaves the item. Returns true if the event
+    /// was accepted.
+    fn hover_leave_event(&mut self, _event: QHoverEvent) -> bool {
+        false
+    }
+
+    /// Handle mouse wheel and touchpad scroll events. Returns true if the event was accepted.
+    ///
+    /// Ignored events are propagated to the items below.
+    fn wheel_event(&mut self, _event: QWheelEvent) -> bool {
+        false
+    }
+
+    /// Handle touch events. Returns true if the event was accepted.
+    ///
+    /// Touch events are only sent if the item accepts them (see `set_accept_touch_events`),
+    /// otherwise they are converted to mouse events.
+    /// If the `TouchBegin` event is ignored, the item does not receive the other events of
+    /// that touch sequence.
+    fn touch_event(&mut self, _event: QTouchEvent) -> bool {
+        false
+    }
+
+    /// Called when the item gets the active focus
+    ///
+    /// If the focus is changed from a function which borrows this item mutably, like
+    /// `mouse_event`, it is called later from the event loop.
+    fn focus_in_event(&mut self, _event: QFocusEvent) {}
+
+    /// Called when the item loses the active focus
+    ///
+    /// Like `focus_in_event`, it is called later if this item is borrowed.
+    fn focus_out_event(&mut self, _event: QFocusEvent) {}
+
+    fn geometry_changed(&mut self, _new_geometry: QRectF, _old_geometry: QRectF) {}
+
+    /// Called when the item changes (see QQuickItem::itemChange)
+    ///
+    /// This is called during the change. If the change is done from a function which borrows
+    /// this item mutably, like `component_complete`, it is called later from the event loop.
+    fn item_change(&mut self, _change: ItemChange) {}
+
+    fn update_paint_node(&mut self, node: SGNode<ContainerNode>) -> SGNode<ContainerNode> {
+        node
+    }
+}
+
+/// The input events handled by `dispatch_input_event`, mapped from QEvent::Type in C++
+#[repr(C)]
+#[allow(dead_code)] // The variants are only created in C++
+enum InputEventKind {
+    Other,
+    KeyPress,
+    KeyRelease,
+    FocusIn,
+    FocusOut,
+    Wheel,
+    HoverEnter,
+    HoverLeave,
+    HoverMove,
+    Touch,
+}
+
+cpp! {{
+    #include <QtCore/QEvent>
+
+    enum class RustInputEventKind {
+        Other,
+        KeyPress,
+        KeyRelease,
+        FocusIn,
+        FocusOut,
+        Wheel,
+        HoverEnter,
+        HoverLeave,
+        HoverMove,
+        Touch,
+    };
+}}
+
+/// Calls the QQuickItem function handling this event, and returns whether it was accepted
+fn dispatch_input_event<T: QQuickItem + ?Sized>(item: &mut T, event: *const c_void) -> bool {
+    let kind = cpp!(unsafe [event as "QEvent *"] -> InputEventKind as "RustInputEventKind" {
+        switch (event->type()) {
+        case QEvent::KeyPress: return RustInputEventKind::KeyPress;
+        case QEvent::KeyRelease: return RustInputEventKind::KeyRelease;
+        case QEvent::FocusIn: return RustInputEventKind::FocusIn;
+        case QEvent::FocusOut: return RustInputEventKind::FocusOut;
+        case QEvent::Wheel: return RustInputEventKind::Wheel;
+        case QEvent::HoverEnter: return RustInputEventKind::HoverEnter;
+        case QEvent::HoverLeave: return RustInputEventKind::HoverLeave;
+        case QEvent::HoverMove: return RustInputEventKind::HoverMove;
+        case QEvent::TouchBegin:
+        case QEvent::TouchUpdate:
+        case QEvent::TouchEnd:
+        case QEvent::TouchCancel:
+            return RustInputEventKind::Touch;
+        default: return RustInputEventKind::Other;
+        }
+    });
+    let marker = std::marker::PhantomData;
+    match kind {
+        InputEventKind::KeyPress => item.key_press_event(QKeyEvent(event, marker)),
+        InputEventKind::KeyRelease => item.key_release_event(QKeyEvent(event, marker)),
+        InputEventKind::FocusIn => {
+            item.focus_in_event(QFocusEvent(event, marker));
+            true
+        }
+        InputEventKind::FocusOut => {
+            item.focus_out_event(QFocusEvent(event, marker));
+            true
+        }
+        InputEventKind::Wheel => item.wheel_event(QWheelEvent(event, marker)),
+        InputEventKind::HoverEnter => item.hover_enter_event(QHoverEvent(event, marker)),
+        InputEventKind::HoverLeave => item.hover_leave_event(QHoverEvent(event, marker)),
+        InputEventKind::HoverMove => item.hover_move_event(QHoverEvent(event, marker)),
+        InputEventKind::Touch => item.touch_event(QTouchEvent(event, marker)),
+        InputEventKind::Other => false,
+    }
+}
+
+cpp! {{
+    #include <qmetaobject_rust.hpp>
+    #include <QtQuick/QQuickItem>
+    #include <QtGui/QCursor>
+    #include <QtCore/QTimer>
+
+    #if QT_VERSION < QT_VERSION_CHECK(6, 0, 0)
+        #define QT_QQUICKITEM_GEOMETRYCHANGE geometryChanged
+    #else
+        #define QT_QQUICKITEM_GEOMETRYCHANGE geometryChange
+    #endif
+
+    enum class RustItemChangeKind {
+        Other,
+        ChildAdded,
+        ChildRemoved,
+        SceneChange,
+        VisibleHasChanged,
+        ParentHasChanged,
+        OpacityHasChanged,
+        ActiveFocusHasChanged,
+        RotationHasChanged,
+        AntialiasingHasChanged,
+        DevicePixelRatioHasChanged,
+        EnabledHasChanged,
+    };
+
+    // Maps the change to Rust, and extracts the member of the union which is used by this change
+    static RustItemChangeKind itemChangeToRust(QQuickItem::ItemChange change, const QQuickItem::ItemChangeData &data,
+                                               QObject **object, bool *bool_value, qreal *real_value) {
+        switch (change) {
+        case QQuickItem::ItemChildAddedChange:
+            *object = data.item;
+            return RustItemChangeKind::ChildAdded;
+        case QQuickItem::ItemChildRemovedChange:
+            *object = data.item;
+            return RustItemChangeKind::ChildRemoved;
+        case QQuickItem::ItemSceneChange:
+            *object = data.window;
+            return RustItemChangeKind::SceneChange;
+        case QQuickItem::ItemVisibleHasChanged:
+            *bool_value = data.boolValue;
+            return RustItemChangeKind::VisibleHasChanged;
+        case QQuickItem::ItemParentHasChanged:
+            *object = data.item;
+            return RustItemChangeKind::ParentHasChanged;
+        case QQuickItem::ItemOpacityHasChanged:
+            *real_value = data.realValue;
+            return RustItemChangeKind::OpacityHasChanged;
+        case QQuickItem::ItemActiveFocusHasChanged:
+            *bool_value = data.boolValue;
+            return RustItemChangeKind::ActiveFocusHasChanged;
+        case QQuickItem::ItemRotationHasChanged:
+            *real_value = data.realValue;
+            return RustItemChangeKind::RotationHasChanged;
+        case QQuickItem::ItemAntialiasingHasChanged:
+            *bool_value = data.boolValue;
+            return RustItemChangeKind::AntialiasingHasChanged;
+        case QQuickItem::ItemDevicePixelRatioHasChanged:
+            *real_value = data.realValue;
+            return RustItemChangeKind::DevicePixelRatioHasChanged;
+    #if QT_VERSION >= QT_VERSION_CHECK(5, 10, 0)
+        case QQuickItem::ItemEnabledHasChanged:
+            *bool_value = data.boolValue;
+            return RustItemChangeKind::EnabledHasChanged;
+    #endif
+        default:
+            return RustItemChangeKind::Other;
+        }
+    }
 
     struct Rust_QQuickItem : RustObject<QQuickItem> {
     /*
@@ -829,8 +1727,7 @@ cpp! {{
         virtual bool contains(const QPointF &point) const;
         virtual QVariant inputMethodQuery(Qt::InputMethodQuery query) const;
         virtual bool isTextureProvider() const;
-        virtual QSGTextureProvider *textureProvider() const;
-        virtual void itemChange(ItemChange, const ItemChangeData &);*/
+        virtual QSGTextureProvider *textureProvider() const;*/
         void classBegin() override {
             QQuickItem::classBegin();
             rust!(Rust_QQuickItem_classBegin[
@@ -849,11 +1746,17 @@ cpp! {{
             });
         }
 
-        /*virtual void keyPressEvent(QKeyEvent *event);
-        virtual void keyReleaseEvent(QKeyEvent *event);
-        virtual void inputMethodEvent(QInputMethodEvent *);
-        virtual void focusInEvent(QFocusEvent *);
-        virtual void focusOutEvent(QFocusEvent *);*/
+        void keyPressEvent(QKeyEvent *event) override { handleInputEvent(event); }
+        void keyReleaseEvent(QKeyEvent *event) override { handleInputEvent(event); }
+        //virtual void inputMethodEvent(QInputMethodEvent *);
+        void focusInEvent(QFocusEvent *event) override {
+            QQuickItem::focusInEvent(event);
+            handleFocusEvent(event);
+        }
+        void focusOutEvent(QFocusEvent *event) override {
+            QQuickItem::focusOutEvent(event);
+            handleFocusEvent(event);
+        }
 
         void mousePressEvent(QMouseEvent *event) override { handleMouseEvent(event); }
         void mouseMoveEvent(QMouseEvent *event) override { handleMouseEvent(event); }
@@ -869,14 +1772,46 @@ cpp! {{
             })) { event->ignore(); }
         }
 
+        void wheelEvent(QWheelEvent *event) override { handleInputEvent(event); }
+        void touchEvent(QTouchEvent *event) override { handleInputEvent(event); }
+        void hoverEnterEvent(QHoverEvent *event) override { handleInputEvent(event); }
+        void hoverMoveEvent(QHoverEvent *event) override { handleInputEvent(event); }
+        void hoverLeaveEvent(QHoverEvent *event) override { handleInputEvent(event); }
+
+        void handleInputEvent(QEvent *event) {
+            event->setAccepted(rust!(Rust_QQuickItem_inputEvent[
+                rust_object: QObjectPinned<dyn QQuickItem> as "TraitObject",
+                event: *const c_void as "QEvent *"
+            ] -> bool as "bool" {
+                dispatch_input_event(&mut *rust_object.borrow_mut(), event)
+            }));
+        }
+
+        void handleFocusEvent(QFocusEvent *event) {
+            if (!rust!(Rust_QQuickItem_focusEvent[
+                rust_object: QObjectPinned<dyn QQuickItem> as "TraitObject",
+                event: *const c_void as "QEvent *"
+            ] -> bool as "bool" {
+                if let Some(mut item) = rust_object.try_borrow_mut() {
+                    dispatch_input_event(&mut *item, event);
+                    true
+                } else {
+                    false
+                }
+            })) {
+                // The focus was changed from a function which borrows the item
+                QEvent::Type type = event->type();
+                Qt::FocusReason reason = event->reason();
+                QTimer::singleShot(0, this, [=] {
+                    QFocusEvent focus_event(type, reason);
+                    handleFocusEvent(&focus_event);
+                });
+            }
+        }
+
         /*
         virtual void mouseUngrabEvent(); // XXX todo - params?
         virtual void touchUngrabEvent();
-        virtual void wheelEvent(QWheelEvent *event);
-        virtual void touchEvent(QTouchEvent *event);
-        virtual void hoverEnterEvent(QHoverEvent *event);
-        virtual void hoverMoveEvent(QHoverEvent *event);
-        virtual void hoverLeaveEvent(QHoverEvent *event);
         virtual void dragEnterEvent(QDragEnterEvent *);
         virtual void dragMoveEvent(QDragMoveEvent *);
         virtual void dragLeaveEvent(QDragLeaveEvent *);
@@ -913,6 +1848,40 @@ cpp! {{
                 rust_object.borrow_mut().release_resources();
             });
         }
+
+        void itemChange(ItemChange change, const ItemChangeData &data) override {
+            QQuickItem::itemChange(change, data);
+            QObject *object = nullptr;
+            bool bool_value = false;
+            qreal real_value = 0;
+            RustItemChangeKind kind = itemChangeToRust(change, data, &object, &bool_value, &real_value);
+            notifyItemChange(kind, object, bool_value, real_value);
+        }
+
+        void notifyItemChange(RustItemChangeKind kind, QObject *object, bool bool_value, qreal real_value) {
+            if (!rust!(Rust_QQuickItem_itemChange[
+                rust_object: QObjectPinned<dyn QQuickItem> as "TraitObject",
+                kind: ItemChangeKind as "RustItemChangeKind",
+                object: *mut c_void as "QObject *",
+                bool_value: bool as "bool",
+                real_value: f64 as "qreal"
+            ] -> bool as "bool" {
+                if let Some(mut item) = rust_object.try_borrow_mut() {
+                    if let Some(change) = ItemChange::from_raw(kind, object, bool_value, real_value) {
+                        item.item_change(change);
+                    }
+                    true
+                } else {
+                    false
+                }
+            })) {
+                // The change was done from a function which borrows the item
+                QPointer<QObject> object_ptr = object;
+                QTimer::singleShot(0, this, [=] {
+                    notifyItemChange(kind, object_ptr, bool_value, real_value);
+                });
+            }
+        }
         /*
         virtual void updatePolish();
         */
@@ -933,6 +1902,339 @@ impl<'a> dyn QQuickItem + 'a {
             if (obj) obj->update();
         });
     }
+
+    /// Refer to the Qt documentation of QQuickItem::flags
+    pub fn flags(&self) -> QQuickItemFlags {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *"] -> QQuickItemFlags as "int" {
+            return obj ? int(obj->flags()) : 0;
+        })
+    }
+
+    /// Enables or disables all the given flags (see QQuickItem::setFlag)
+    ///
+    /// For example, `ITEM_HAS_CONTENTS` must be set for `update_paint_node` to be called.
+    pub fn set_flag(&self, flags: QQuickItemFlags, enabled: bool) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *", flags as "int", enabled as "bool"] {
+            if (!obj)
+                return;
+            for (int bit = 1; bit > 0 && bit <= flags; bit <<= 1) {
+                if (flags & bit)
+                    obj->setFlag(QQuickItem::Flag(bit), enabled);
+            }
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickItem::acceptedMouseButtons
+    pub fn accepted_mouse_buttons(&self) -> MouseButtons {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *"] -> MouseButtons as "int" {
+            return obj ? int(obj->acceptedMouseButtons()) : 0;
+        })
+    }
+
+    /// Sets the mouse buttons for which `mouse_event` is called
+    /// (see QQuickItem::setAcceptedMouseButtons)
+    pub fn set_accepted_mouse_buttons(&self, buttons: MouseButtons) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *", buttons as "int"] {
+            if (obj) obj->setAcceptedMouseButtons(Qt::MouseButtons(buttons));
+        });
+    }
+
+    /// Sets whether the hover events are sent to the item (see QQuickItem::setAcceptHoverEvents)
+    pub fn set_accept_hover_events(&self, enabled: bool) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *", enabled as "bool"] {
+            if (obj) obj->setAcceptHoverEvents(enabled);
+        });
+    }
+
+    /// Sets whether the touch events are sent to the item (see QQuickItem::setAcceptTouchEvents)
+    ///
+    /// Before Qt 5.10, the touch events are always sent.
+    pub fn set_accept_touch_events(&self, enabled: bool) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *", enabled as "bool"] {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 10, 0)
+                if (obj) obj->setAcceptTouchEvents(enabled);
+            #else
+                Q_UNUSED(obj);
+                Q_UNUSED(enabled);
+            #endif
+        });
+    }
+
+    /// Sets the implicitWidth and implicitHeight properties of the item
+    pub fn set_implicit_size(&self, width: qreal, height: qreal) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *", width as "qreal", height as "qreal"] {
+            if (!obj)
+                return;
+            obj->setImplicitWidth(width);
+            obj->setImplicitHeight(height);
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickItem::hasFocus
+    pub fn has_focus(&self) -> bool {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *"] -> bool as "bool" {
+            return obj && obj->hasFocus();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQuickItem::hasActiveFocus
+    pub fn has_active_focus(&self) -> bool {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *"] -> bool as "bool" {
+            return obj && obj->hasActiveFocus();
+        })
+    }
+
+    /// Sets the focus property of the item, within its focus scope (see QQuickItem::setFocus)
+    pub fn set_focus(&self, focus: bool) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *", focus as "bool"] {
+            if (obj) obj->setFocus(focus);
+        });
+    }
+
+    /// Gives the active focus to the item, and to its enclosing focus scopes
+    /// (see QQuickItem::forceActiveFocus)
+    pub fn force_active_focus(&self) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *"] {
+            if (obj) obj->forceActiveFocus();
+        });
+    }
+
+    /// Sends the mouse events to this item until `ungrab_mouse` is called or the mouse is
+    /// released (see QQuickItem::grabMouse)
+    pub fn grab_mouse(&self) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *"] {
+            if (obj) obj->grabMouse();
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickItem::ungrabMouse
+    pub fn ungrab_mouse(&self) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *"] {
+            if (obj) obj->ungrabMouse();
+        });
+    }
+
+    /// Sets the shape of the mouse cursor over the item (see QQuickItem::setCursor)
+    pub fn set_cursor(&self, shape: CursorShape) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *", shape as "Qt::CursorShape"] {
+            #if !defined(QT_NO_CURSOR)
+                if (obj) obj->setCursor(QCursor(shape));
+            #else
+                Q_UNUSED(obj);
+                Q_UNUSED(shape);
+            #endif
+        });
+    }
+
+    /// Restores the default cursor (see QQuickItem::unsetCursor)
+    pub fn unset_cursor(&self) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *"] {
+            #if !defined(QT_NO_CURSOR)
+                if (obj) obj->unsetCursor();
+            #else
+                Q_UNUSED(obj);
+            #endif
+        });
+    }
+
+    /// Returns the visual parent of the item, which may be different from the QObject parent
+    /// (see QQuickItem::parentItem)
+    pub fn parent_item(&self) -> QObjectRef {
+        let obj = self.get_cpp_object();
+        unsafe {
+            QObjectRef::from_cpp_ptr(cpp!([obj as "QQuickItem *"] -> *mut c_void as "QObject *" {
+                return obj ? obj->parentItem() : nullptr;
+            }))
+        }
+    }
+
+    /// Sets the visual parent of the item (see QQuickItem::setParentItem)
+    ///
+    /// Does nothing if the parent is not a QQuickItem. Passing a null handle removes the item
+    /// from the scene.
+    pub fn set_parent_item(&self, parent: &QObjectRef) {
+        let obj = self.get_cpp_object();
+        let parent = parent.as_cpp_ptr();
+        cpp!(unsafe [obj as "QQuickItem *", parent as "QObject *"] {
+            auto parent_item = qobject_cast<QQuickItem *>(parent);
+            if (obj && (parent_item || !parent))
+                obj->setParentItem(parent_item);
+        });
+    }
+
+    /// Returns the visual children of the item, in stacking order (see QQuickItem::childItems)
+    pub fn child_items(&self) -> Vec<QObjectRef> {
+        let obj = self.get_cpp_object();
+        let mut result = Vec::new();
+        let result_ptr = &mut result;
+        cpp!(unsafe [obj as "QQuickItem *", result_ptr as "void *"] {
+            if (!obj)
+                return;
+            QList<QObject *> children;
+            for (QQuickItem *child : obj->childItems())
+                children.append(child);
+            qobjectsToRust(children, result_ptr);
+        });
+        result
+    }
+
+    /// Maps a point in the coordinates of this item to the coordinates of `item`
+    /// (see QQuickItem::mapToItem)
+    ///
+    /// If `item` is a null handle or not a QQuickItem, the point is mapped to the scene.
+    pub fn map_to_item(&self, item: &QObjectRef, point: QPointF) -> QPointF {
+        let obj = self.get_cpp_object();
+        let item = item.as_cpp_ptr();
+        cpp!(unsafe [obj as "QQuickItem *", item as "QObject *", point as "QPointF"] -> QPointF as "QPointF" {
+            return obj ? obj->mapToItem(qobject_cast<QQuickItem *>(item), point) : point;
+        })
+    }
+
+    /// Maps a point in the coordinates of `item` to the coordinates of this item
+    /// (see QQuickItem::mapFromItem)
+    ///
+    /// If `item` is a null handle or not a QQuickItem, the point is mapped from the scene.
+    pub fn map_from_item(&self, item: &QObjectRef, point: QPointF) -> QPointF {
+        let obj = self.get_cpp_object();
+        let item = item.as_cpp_ptr();
+        cpp!(unsafe [obj as "QQuickItem *", item as "QObject *", point as "QPointF"] -> QPointF as "QPointF" {
+            return obj ? obj->mapFromItem(qobject_cast<QQuickItem *>(item), point) : point;
+        })
+    }
+
+    /// Maps a point in the coordinates of this item to the coordinates of the window
+    /// (see QQuickItem::mapToScene)
+    pub fn map_to_scene(&self, point: QPointF) -> QPointF {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *", point as "QPointF"] -> QPointF as "QPointF" {
+            return obj ? obj->mapToScene(point) : point;
+        })
+    }
+
+    /// Maps a point in the coordinates of the window to the coordinates of this item
+    /// (see QQuickItem::mapFromScene)
+    pub fn map_from_scene(&self, point: QPointF) -> QPointF {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickItem *", point as "QPointF"] -> QPointF as "QPointF" {
+            return obj ? obj->mapFromScene(point) : point;
+        })
+    }
+}
+
+/// Bindings for [`QQuickItem::Flags`][enum] flags.
+///
+/// The flags can be combined with the `|` operator.
+///
+/// [enum]: https://doc.qt.io/qt-5/qquickitem.html#Flag-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct QQuickItemFlags(pub i32);
+
+impl QQuickItemFlags {
+    pub const ITEM_CLIPS_CHILDREN_TO_SHAPE: QQuickItemFlags = QQuickItemFlags(0x01);
+    pub const ITEM_ACCEPTS_INPUT_METHOD: QQuickItemFlags = QQuickItemFlags(0x02);
+    pub const ITEM_IS_FOCUS_SCOPE: QQuickItemFlags = QQuickItemFlags(0x04);
+    pub const ITEM_HAS_CONTENTS: QQuickItemFlags = QQuickItemFlags(0x08);
+    pub const ITEM_ACCEPTS_DROPS: QQuickItemFlags = QQuickItemFlags(0x10);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: QQuickItemFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for QQuickItemFlags {
+    type Output = QQuickItemFlags;
+    fn bitor(self, other: QQuickItemFlags) -> QQuickItemFlags {
+        QQuickItemFlags(self.0 | other.0)
+    }
+}
+
+/// A change of a QQuickItem, passed to `QQuickItem::item_change`.
+///
+/// Refer to the Qt documentation of [`QQuickItem::ItemChange`][enum].
+/// The items and windows are given as QObjectRef.
+///
+/// [enum]: https://doc.qt.io/qt-5/qquickitem.html#ItemChange-enum
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ItemChange {
+    ChildAdded(QObjectRef),
+    ChildRemoved(QObjectRef),
+    /// The item was added to a window, or removed from it (null handle)
+    SceneChange(QObjectRef),
+    VisibleHasChanged(bool),
+    /// The visual parent changed, the handle is null if the item has no parent
+    ParentHasChanged(QObjectRef),
+    OpacityHasChanged(qreal),
+    ActiveFocusHasChanged(bool),
+    RotationHasChanged(qreal),
+    AntialiasingHasChanged(bool),
+    DevicePixelRatioHasChanged(qreal),
+    /// Only sent since Qt 5.10
+    EnabledHasChanged(bool),
+}
+
+/// The changes passed to `item_change`, mapped from QQuickItem::ItemChange in C++
+#[repr(C)]
+#[allow(dead_code)] // The variants are only created in C++
+enum ItemChangeKind {
+    Other,
+    ChildAdded,
+    ChildRemoved,
+    SceneChange,
+    VisibleHasChanged,
+    ParentHasChanged,
+    OpacityHasChanged,
+    ActiveFocusHasChanged,
+    RotationHasChanged,
+    AntialiasingHasChanged,
+    DevicePixelRatioHasChanged,
+    EnabledHasChanged,
+}
+
+impl ItemChange {
+    fn from_raw(
+        kind: ItemChangeKind,
+        object: *mut c_void,
+        bool_value: bool,
+        real_value: qreal,
+    ) -> Option<ItemChange> {
+        let object = || unsafe { QObjectRef::from_cpp_ptr(object) };
+        Some(match kind {
+            ItemChangeKind::ChildAdded => ItemChange::ChildAdded(object()),
+            ItemChangeKind::ChildRemoved => ItemChange::ChildRemoved(object()),
+            ItemChangeKind::SceneChange => ItemChange::SceneChange(object()),
+            ItemChangeKind::VisibleHasChanged => ItemChange::VisibleHasChanged(bool_value),
+            ItemChangeKind::ParentHasChanged => ItemChange::ParentHasChanged(object()),
+            ItemChangeKind::OpacityHasChanged => ItemChange::OpacityHasChanged(real_value),
+            ItemChangeKind::ActiveFocusHasChanged => ItemChange::ActiveFocusHasChanged(bool_value),
+            ItemChangeKind::RotationHasChanged => ItemChange::RotationHasChanged(real_value),
+            ItemChangeKind::AntialiasingHasChanged => {
+                ItemChange::AntialiasingHasChanged(bool_value)
+            }
+            ItemChangeKind::DevicePixelRatioHasChanged => {
+                ItemChange::DevicePixelRatioHasChanged(real_value)
+            }
+            ItemChangeKind::EnabledHasChanged => ItemChange::EnabledHasChanged(bool_value),
+            ItemChangeKind::Other => return None,
+        })
+    }
 }
 
 /// Only a specific subset of [`QEvent::Type`][qt] enum.
@@ -968,14 +2270,340 @@ impl<'a> QMouseEvent<'a> {
             return self->localPos();
         })
     }
+    /// Return the position in the window, wrapper around Qt's QMouseEvent::scenePosition()
+    pub fn scene_position(self) -> QPointF {
+        cpp!(unsafe [self as "QMouseEvent *"] -> QPointF as "QPointF" {
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+                return self->scenePosition();
+            #else
+                return self->windowPos();
+            #endif
+        })
+    }
+    /// Returns the button that caused the event (none for move events)
+    pub fn button(self) -> MouseButtons {
+        cpp!(unsafe [self as "QMouseEvent *"] -> MouseButtons as "int" {
+            return int(self->button());
+        })
+    }
+    /// Returns the buttons that are pressed
+    pub fn buttons(self) -> MouseButtons {
+        cpp!(unsafe [self as "QMouseEvent *"] -> MouseButtons as "int" {
+            return int(self->buttons());
+        })
+    }
+    /// Returns the keyboard modifiers at the time of the event
+    pub fn modifiers(self) -> KeyboardModifiers {
+        cpp!(unsafe [self as "QMouseEvent *"] -> KeyboardModifiers as "int" {
+            return int(self->modifiers());
+        })
+    }
+    /// Returns the time of the event, in milliseconds
+    pub fn timestamp(self) -> u64 {
+        cpp!(unsafe [self as "QMouseEvent *"] -> u64 as "quint64" {
+            return self->timestamp();
+        })
+    }
+}
+
+/// A reference to a [`QKeyEvent`][qt] instance.
+///
+/// [qt]: https://doc.qt.io/qt-5/qkeyevent.html
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct QKeyEvent<'a>(*const c_void, std::marker::PhantomData<&'a u32>);
+
+impl<'a> QKeyEvent<'a> {
+    /// Returns the code of the key, a value of the [`Qt::Key`][enum] enum
+    ///
+    /// [enum]: https://doc.qt.io/qt-5/qt.html#Key-enum
+    pub fn key(self) -> i32 {
+        cpp!(unsafe [self as "QKeyEvent *"] -> i32 as "int" {
+            return self->key();
+        })
+    }
+    /// Returns the text generated by the key, which is empty for the modifier keys
+    pub fn text(self) -> QString {
+        cpp!(unsafe [self as "QKeyEvent *"] -> QString as "QString" {
+            return self->text();
+        })
+    }
+    /// Returns the keyboard modifiers at the time of the event
+    pub fn modifiers(self) -> KeyboardModifiers {
+        cpp!(unsafe [self as "QKeyEvent *"] -> KeyboardModifiers as "int" {
+            return int(self->modifiers());
+        })
+    }
+    /// Returns true if the event comes from a key being held down
+    pub fn is_auto_repeat(self) -> bool {
+        cpp!(unsafe [self as "QKeyEvent *"] -> bool as "bool" {
+            return self->isAutoRepeat();
+        })
+    }
+    /// Returns the time of the event, in milliseconds
+    pub fn timestamp(self) -> u64 {
+        cpp!(unsafe [self as "QKeyEvent *"] -> u64 as "quint64" {
+            return self->timestamp();
+        })
+    }
+}
+
+/// A reference to a [`QHoverEvent`][qt] instance.
+///
+/// [qt]: https://doc.qt.io/qt-5/qhoverevent.html
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct QHoverEvent<'a>(*const c_void, std::marker::PhantomData<&'a u32>);
+
+impl<'a> QHoverEvent<'a> {
+    /// Returns the position of the mouse in the item
+    pub fn position(self) -> QPointF {
+        cpp!(unsafe [self as "QHoverEvent *"] -> QPointF as "QPointF" {
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+                return self->position();
+            #else
+                return self->posF();
+            #endif
+        })
+    }
+    /// Returns the previous position of the mouse in the item
+    pub fn old_position(self) -> QPointF {
+        cpp!(unsafe [self as "QHoverEvent *"] -> QPointF as "QPointF" {
+            return self->oldPosF();
+        })
+    }
+    /// Returns the keyboard modifiers at the time of the event
+    pub fn modifiers(self) -> KeyboardModifiers {
+        cpp!(unsafe [self as "QHoverEvent *"] -> KeyboardModifiers as "int" {
+            return int(self->modifiers());
+        })
+    }
+    /// Returns the time of the event, in milliseconds
+    pub fn timestamp(self) -> u64 {
+        cpp!(unsafe [self as "QHoverEvent *"] -> u64 as "quint64" {
+            return self->timestamp();
+        })
+    }
+}
+
+/// A reference to a [`QWheelEvent`][qt] instance.
+///
+/// [qt]: https://doc.qt.io/qt-5/qwheelevent.html
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct QWheelEvent<'a>(*const c_void, std::marker::PhantomData<&'a u32>);
+
+impl<'a> QWheelEvent<'a> {
+    /// Returns the position of the mouse in the item
+    pub fn position(self) -> QPointF {
+        cpp!(unsafe [self as "QWheelEvent *"] -> QPointF as "QPointF" {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 14, 0)
+                return self->position();
+            #else
+                return self->posF();
+            #endif
+        })
+    }
+    /// Returns the rotation of the wheel, in eighths of a degree
+    ///
+    /// Most mice move by steps of 15 degrees, which is a delta of 120.
+    /// `y` is the vertical wheel, and is positive when the wheel is rotated away from the user.
+    pub fn angle_delta(self) -> QPointF {
+        cpp!(unsafe [self as "QWheelEvent *"] -> QPointF as "QPointF" {
+            return QPointF(self->angleDelta());
+        })
+    }
+    /// Returns the scrolling distance in pixels, or (0, 0) if the device does not provide it
+    /// (typically high-resolution touchpads do)
+    pub fn pixel_delta(self) -> QPointF {
+        cpp!(unsafe [self as "QWheelEvent *"] -> QPointF as "QPointF" {
+            return QPointF(self->pixelDelta());
+        })
+    }
+    /// Returns true if the deltas are inverted, to follow the "natural scrolling" setting
+    pub fn inverted(self) -> bool {
+        cpp!(unsafe [self as "QWheelEvent *"] -> bool as "bool" {
+            return self->inverted();
+        })
+    }
+    /// Returns the buttons that are pressed
+    pub fn buttons(self) -> MouseButtons {
+        cpp!(unsafe [self as "QWheelEvent *"] -> MouseButtons as "int" {
+            return int(self->buttons());
+        })
+    }
+    /// Returns the keyboard modifiers at the time of the event
+    pub fn modifiers(self) -> KeyboardModifiers {
+        cpp!(unsafe [self as "QWheelEvent *"] -> KeyboardModifiers as "int" {
+            return int(self->modifiers());
+        })
+    }
+    /// Returns the time of the event, in milliseconds
+    pub fn timestamp(self) -> u64 {
+        cpp!(unsafe [self as "QWheelEvent *"] -> u64 as "quint64" {
+            return self->timestamp();
+        })
+    }
+}
+
+/// The touch subset of the [`QEvent::Type`][qt] enum.
+///
+/// [qt]: https://doc.qt.io/qt-5/qevent.html#Type-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QTouchEventType {
+    TouchBegin = 194,
+    TouchUpdate = 195,
+    TouchEnd = 196,
+    /// The touch sequence was interrupted, for example by a gesture
+    TouchCancel = 209,
+}
+
+/// The state of a touch point, see [`Qt::TouchPointState`][qt].
+///
+/// [qt]: https://doc.qt.io/qt-5/qt.html#TouchPointState-enum
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TouchPointState {
+    Pressed,
+    Moved,
+    Stationary,
+    Released,
+    Unknown,
+}
+
+impl Default for TouchPointState {
+    fn default() -> Self {
+        TouchPointState::Unknown
+    }
+}
+
+/// A touch point of a QTouchEvent
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct TouchPoint {
+    /// Identifies the point during the touch sequence
+    pub id: i32,
+    pub state: TouchPointState,
+    /// The position in the item
+    pub position: QPointF,
+    /// The position in the window
+    pub scene_position: QPointF,
+    /// The pressure between 0 and 1, or 1 if the device does not report it
+    pub pressure: f64,
+}
+
+/// A reference to a [`QTouchEvent`][qt] instance.
+///
+/// [qt]: https://doc.qt.io/qt-5/qtouchevent.html
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct QTouchEvent<'a>(*const c_void, std::marker::PhantomData<&'a u32>);
+
+impl<'a> QTouchEvent<'a> {
+    /// Returns the type of event
+    pub fn event_type(self) -> QTouchEventType {
+        cpp!(unsafe [self as "QTouchEvent *"] -> QTouchEventType as "int" {
+            return self->type();
+        })
+    }
+    /// Returns the touch points of the event
+    pub fn touch_points(self) -> Vec<TouchPoint> {
+        let count = cpp!(unsafe [self as "QTouchEvent *"] -> usize as "size_t" {
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+                return self->points().size();
+            #else
+                return self->touchPoints().size();
+            #endif
+        });
+        (0..count)
+            .map(|i| {
+                let mut point = TouchPoint::default();
+                let mut state = 0;
+                let (id, state_ptr, position, scene_position, pressure) = (
+                    &mut point.id,
+                    &mut state,
+                    &mut point.position,
+                    &mut point.scene_position,
+                    &mut point.pressure,
+                );
+                cpp!(unsafe [
+                    self as "QTouchEvent *",
+                    i as "size_t",
+                    id as "int *",
+                    state_ptr as "int *",
+                    position as "QPointF *",
+                    scene_position as "QPointF *",
+                    pressure as "qreal *"
+                ] {
+                    #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+                        const auto &point = self->points().at(i);
+                        *position = point.position();
+                        *scene_position = point.scenePosition();
+                    #else
+                        const auto &point = self->touchPoints().at(i);
+                        *position = point.pos();
+                        *scene_position = point.scenePos();
+                    #endif
+                    *id = point.id();
+                    *state_ptr = int(point.state());
+                    *pressure = point.pressure();
+                });
+                point.state = match state {
+                    0x01 => TouchPointState::Pressed,
+                    0x02 => TouchPointState::Moved,
+                    0x04 => TouchPointState::Stationary,
+                    0x08 => TouchPointState::Released,
+                    _ => TouchPointState::Unknown,
+                };
+                point
+            })
+            .collect()
+    }
+    /// Returns the keyboard modifiers at the time of the event
+    pub fn modifiers(self) -> KeyboardModifiers {
+        cpp!(unsafe [self as "QTouchEvent *"] -> KeyboardModifiers as "int" {
+            return int(self->modifiers());
+        })
+    }
+    /// Returns the time of the event, in milliseconds
+    pub fn timestamp(self) -> u64 {
+        cpp!(unsafe [self as "QTouchEvent *"] -> u64 as "quint64" {
+            return self->timestamp();
+        })
+    }
+}
+
+/// A reference to a [`QFocusEvent`][qt] instance.
+///
+/// [qt]: https://doc.qt.io/qt-5/qfocusevent.html
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct QFocusEvent<'a>(*const c_void, std::marker::PhantomData<&'a u32>);
+
+impl<'a> QFocusEvent<'a> {
+    /// Returns the reason of the focus change
+    pub fn reason(self) -> FocusReason {
+        cpp!(unsafe [self as "QFocusEvent *"] -> FocusReason as "int" {
+            return self->reason();
+        })
+    }
 }
 
 cpp_class!(
     /// Wrapper for QJSValue
+    ///
+    /// The default value is `undefined`.
+    #[derive(Clone, Default)]
     pub unsafe struct QJSValue as "QJSValue"
 );
 
 impl QJSValue {
+    /// Returns a `null` value (see QJSValue::NullValue)
+    pub fn null() -> QJSValue {
+        cpp!(unsafe [] -> QJSValue as "QJSValue" {
+            return QJSValue(QJSValue::NullValue);
+        })
+    }
+
     pub fn is_bool(&self) -> bool {
         cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
             return self->isBool();
@@ -994,6 +2622,143 @@ impl QJSValue {
         })
     }
 
+    pub fn is_object(&self) -> bool {
+        cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
+            return self->isObject();
+        })
+    }
+
+    pub fn is_array(&self) -> bool {
+        cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
+            return self->isArray();
+        })
+    }
+
+    pub fn is_callable(&self) -> bool {
+        cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
+            return self->isCallable();
+        })
+    }
+
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
+            return self->isNull();
+        })
+    }
+
+    pub fn is_undefined(&self) -> bool {
+        cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
+            return self->isUndefined();
+        })
+    }
+
+    pub fn is_error(&self) -> bool {
+        cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
+            return self->isError();
+        })
+    }
+
+    pub fn is_qobject(&self) -> bool {
+        cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
+            return self->isQObject();
+        })
+    }
+
+    /// Returns the property with the given name, or `undefined` if there is none
+    /// (see QJSValue::property)
+    pub fn property(&self, name: QString) -> QJSValue {
+        cpp!(unsafe [self as "const QJSValue *", name as "QString"] -> QJSValue as "QJSValue" {
+            return self->property(name);
+        })
+    }
+
+    /// Refer to the Qt documentation of QJSValue::setProperty
+    ///
+    /// This does nothing if this value is not an object.
+    pub fn set_property(&mut self, name: QString, value: QJSValue) {
+        cpp!(unsafe [self as "QJSValue *", name as "QString", value as "QJSValue"] {
+            self->setProperty(name, value);
+        })
+    }
+
+    /// Returns the property at the given index, typically an element of an array
+    /// (see QJSValue::property(quint32))
+    pub fn property_at(&self, index: u32) -> QJSValue {
+        cpp!(unsafe [self as "const QJSValue *", index as "quint32"] -> QJSValue as "QJSValue" {
+            return self->property(index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QJSValue::setProperty(quint32, const QJSValue &)
+    pub fn set_property_at(&mut self, index: u32, value: QJSValue) {
+        cpp!(unsafe [self as "QJSValue *", index as "quint32", value as "QJSValue"] {
+            self->setProperty(index, value);
+        })
+    }
+
+    pub fn has_property(&self, name: QString) -> bool {
+        cpp!(unsafe [self as "const QJSValue *", name as "QString"] -> bool as "bool" {
+            return self->hasProperty(name);
+        })
+    }
+
+    pub fn delete_property(&mut self, name: QString) -> bool {
+        cpp!(unsafe [self as "QJSValue *", name as "QString"] -> bool as "bool" {
+            return self->deleteProperty(name);
+        })
+    }
+
+    /// Returns an iterator over the name and value of the enumerable properties of this object
+    /// (see QJSValueIterator)
+    pub fn properties(&self) -> QJSValueIterator {
+        QJSValueIterator::new(self)
+    }
+
+    /// Calls this value as a function with the given arguments (see QJSValue::call)
+    ///
+    /// If the function throws, the returned value is the exception, for which `is_error()`
+    /// usually returns true.
+    pub fn call(&self, args: &[QJSValue]) -> QJSValue {
+        let args_ptr = args.as_ptr();
+        let args_size = args.len();
+        cpp!(unsafe [
+            self as "const QJSValue *",
+            args_ptr as "const QJSValue *",
+            args_size as "size_t"
+        ] -> QJSValue as "QJSValue" {
+            return self->call(qjsValueList(args_ptr, args_size));
+        })
+    }
+
+    /// Same as `call`, but with `instance` as the `this` object
+    /// (see QJSValue::callWithInstance)
+    pub fn call_with_instance(&self, instance: &QJSValue, args: &[QJSValue]) -> QJSValue {
+        let args_ptr = args.as_ptr();
+        let args_size = args.len();
+        cpp!(unsafe [
+            self as "const QJSValue *",
+            instance as "const QJSValue *",
+            args_ptr as "const QJSValue *",
+            args_size as "size_t"
+        ] -> QJSValue as "QJSValue" {
+            return self->callWithInstance(*instance, qjsValueList(args_ptr, args_size));
+        })
+    }
+
+    /// Calls this value as a constructor, as with the `new` operator
+    /// (see QJSValue::callAsConstructor)
+    pub fn call_as_constructor(&self, args: &[QJSValue]) -> QJSValue {
+        let args_ptr = args.as_ptr();
+        let args_size = args.len();
+        cpp!(unsafe [
+            self as "const QJSValue *",
+            args_ptr as "const QJSValue *",
+            args_size as "size_t"
+        ] -> QJSValue as "QJSValue" {
+            return self->callAsConstructor(qjsValueList(args_ptr, args_size));
+        })
+    }
+
     pub fn to_string(&self) -> QString {
         cpp!(unsafe [self as "const QJSValue *"] -> QString as "QString" {
             return self->toString();
@@ -1081,6 +2846,54 @@ impl QMetaType for QJSValue {
     }
 }
 
+/// Iterator over the properties of a QJSValue, returned by `QJSValue::properties`
+///
+/// Wrapper around QJSValueIterator.
+pub struct QJSValueIterator {
+    iterator: *mut c_void,
+}
+
+impl QJSValueIterator {
+    pub fn new(value: &QJSValue) -> QJSValueIterator {
+        let iterator = cpp!(unsafe [value as "const QJSValue *"] -> *mut c_void as "QJSValueIterator *" {
+            return new QJSValueIterator(*value);
+        });
+        QJSValueIterator { iterator }
+    }
+}
+
+impl Iterator for QJSValueIterator {
+    type Item = (QString, QJSValue);
+    fn next(&mut self) -> Option<Self::Item> {
+        let iterator = self.iterator;
+        let mut item = None;
+        let item_ptr = &mut item;
+        cpp!(unsafe [iterator as "QJSValueIterator *", item_ptr as "void *"] {
+            if (!iterator->next())
+                return;
+            QString name = iterator->name();
+            QJSValue value = iterator->value();
+            rust!(Rust_QJSValueIterator_next[
+                item_ptr: &mut Option<(QString, QJSValue)> as "void *",
+                name: QString as "QString",
+                value: QJSValue as "QJSValue"
+            ] {
+                *item_ptr = Some((name, value));
+            });
+        });
+        item
+    }
+}
+
+impl Drop for QJSValueIterator {
+    fn drop(&mut self) {
+        let iterator = self.iterator;
+        cpp!(unsafe [iterator as "QJSValueIterator *"] {
+            delete iterator;
+        })
+    }
+}
+
 #[cfg(test)]
 mod qjsvalue_tests {
     use super::*;
@@ -1120,6 +2933,16 @@ mod qjsvalue_tests {
         assert!(!num_value.is_string());
     }
 
+    #[test]
+    fn test_null_undefined() {
+        assert!(QJSValue::default().is_undefined());
+        assert!(QJSValue::null().is_null());
+        assert!(!QJSValue::null().is_undefined());
+        assert!(!QJSValue::from(42).is_object());
+        assert!(!QJSValue::from(42).is_callable());
+        assert!(QJSValue::from(42).property("foo".into()).is_undefined());
+    }
+
     #[test]
     fn test_qvariantlist_from_iter() {
         let v = vec![1u32, 2u32, 3u32];
@@ -1222,6 +3045,53 @@ cpp! {{
             })) { event->ignore(); }
         }
 
+        void keyPressEvent(QKeyEvent *event) override { handleInputEvent(event); }
+        void keyReleaseEvent(QKeyEvent *event) override { handleInputEvent(event); }
+        void focusInEvent(QFocusEvent *event) override {
+            QQuickPaintedItem::focusInEvent(event);
+            handleFocusEvent(event);
+        }
+        void focusOutEvent(QFocusEvent *event) override {
+            QQuickPaintedItem::focusOutEvent(event);
+            handleFocusEvent(event);
+        }
+        void wheelEvent(QWheelEvent *event) override { handleInputEvent(event); }
+        void touchEvent(QTouchEvent *event) override { handleInputEvent(event); }
+        void hoverEnterEvent(QHoverEvent *event) override { handleInputEvent(event); }
+        void hoverMoveEvent(QHoverEvent *event) override { handleInputEvent(event); }
+        void hoverLeaveEvent(QHoverEvent *event) override { handleInputEvent(event); }
+
+        void handleInputEvent(QEvent *event) {
+            event->setAccepted(rust!(Rust_QQuickPaintedItem_inputEvent[
+                rust_object: QObjectPinned<dyn QQuickPaintedItem> as "TraitObject",
+                event: *const c_void as "QEvent *"
+            ] -> bool as "bool" {
+                dispatch_input_event(&mut *rust_object.borrow_mut(), event)
+            }));
+        }
+
+        void handleFocusEvent(QFocusEvent *event) {
+            if (!rust!(Rust_QQuickPaintedItem_focusEvent[
+                rust_object: QObjectPinned<dyn QQuickPaintedItem> as "TraitObject",
+                event: *const c_void as "QEvent *"
+            ] -> bool as "bool" {
+                if let Some(mut item) = rust_object.try_borrow_mut() {
+                    dispatch_input_event(&mut *item, event);
+                    true
+                } else {
+                    false
+                }
+            })) {
+                // The focus was changed from a function which borrows the item
+                QEvent::Type type = event->type();
+                Qt::FocusReason reason = event->reason();
+                QTimer::singleShot(0, this, [=] {
+                    QFocusEvent focus_event(type, reason);
+                    handleFocusEvent(&focus_event);
+                });
+            }
+        }
+
         void QT_QQUICKITEM_GEOMETRYCHANGE (const QRectF &new_geometry, const QRectF &old_geometry) override{
             rust!(Rust_QQuickPaintedItem_geometryChanged[
                 rust_object: QObjectPinned<dyn QQuickPaintedItem> as "TraitObject",
@@ -1242,6 +3112,40 @@ cpp! {{
             });
         }
 
+        void itemChange(ItemChange change, const ItemChangeData &data) override {
+            QQuickPaintedItem::itemChange(change, data);
+            QObject *object = nullptr;
+            bool bool_value = false;
+            qreal real_value = 0;
+            RustItemChangeKind kind = itemChangeToRust(change, data, &object, &bool_value, &real_value);
+            notifyItemChange(kind, object, bool_value, real_value);
+        }
+
+        void notifyItemChange(RustItemChangeKind kind, QObject *object, bool bool_value, qreal real_value) {
+            if (!rust!(Rust_QQuickPaintedItem_itemChange[
+                rust_object: QObjectPinned<dyn QQuickPaintedItem> as "TraitObject",
+                kind: ItemChangeKind as "RustItemChangeKind",
+                object: *mut c_void as "QObject *",
+                bool_value: bool as "bool",
+                real_value: f64 as "qreal"
+            ] -> bool as "bool" {
+                if let Some(mut item) = rust_object.try_borrow_mut() {
+                    if let Some(change) = ItemChange::from_raw(kind, object, bool_value, real_value) {
+                        item.item_change(change);
+                    }
+                    true
+                } else {
+                    false
+                }
+            })) {
+                // The change was done from a function which borrows the item
+                QPointer<QObject> object_ptr = object;
+                QTimer::singleShot(0, this, [=] {
+                    notifyItemChange(kind, object_ptr, bool_value, real_value);
+                });
+            }
+        }
+
         void paint(QPainter *p) override {
             rust!(Rust_QQuickPaintedItem_paint[
                 rust_object: QObjectPinned<dyn QQuickPaintedItem> as "TraitObject",
@@ -1268,3 +3172,163 @@ pub trait QQuickPaintedItem: QQuickItem {
 
     fn paint(&mut self, _p: &mut QPainter) {}
 }
+
+impl dyn QQuickPaintedItem {
+    /// Refer to the Qt documentation of QQuickPaintedItem::update
+    ///
+    /// Schedules a repaint of the given area only. The rectangle is expanded to integer
+    /// coordinates.
+    pub fn update_rect(&self, rect: QRectF) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *", rect as "QRectF"] {
+            if (obj) obj->update(rect.toAlignedRect());
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::fillColor
+    pub fn fill_color(&self) -> QColor {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *"] -> QColor as "QColor" {
+            return obj ? obj->fillColor() : QColor();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::setFillColor
+    pub fn set_fill_color(&self, color: QColor) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *", color as "QColor"] {
+            if (obj) obj->setFillColor(color);
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::renderTarget
+    pub fn render_target(&self) -> RenderTarget {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *"] -> RenderTarget as "QQuickPaintedItem::RenderTarget" {
+            return obj ? obj->renderTarget() : QQuickPaintedItem::Image;
+        })
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::setRenderTarget
+    pub fn set_render_target(&self, target: RenderTarget) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *", target as "QQuickPaintedItem::RenderTarget"] {
+            if (obj) obj->setRenderTarget(target);
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::antialiasing
+    pub fn antialiasing(&self) -> bool {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *"] -> bool as "bool" {
+            return obj && obj->antialiasing();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::setAntialiasing
+    pub fn set_antialiasing(&self, enabled: bool) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *", enabled as "bool"] {
+            if (obj) obj->setAntialiasing(enabled);
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::mipmap
+    pub fn mipmap(&self) -> bool {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *"] -> bool as "bool" {
+            return obj && obj->mipmap();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::setMipmap
+    pub fn set_mipmap(&self, enabled: bool) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *", enabled as "bool"] {
+            if (obj) obj->setMipmap(enabled);
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::opaquePainting
+    pub fn opaque_painting(&self) -> bool {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *"] -> bool as "bool" {
+            return obj && obj->opaquePainting();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::setOpaquePainting
+    ///
+    /// Only enable this if `paint` fills the whole item with opaque pixels.
+    pub fn set_opaque_painting(&self, opaque: bool) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *", opaque as "bool"] {
+            if (obj) obj->setOpaquePainting(opaque);
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::textureSize
+    pub fn texture_size(&self) -> QSize {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *"] -> QSize as "QSize" {
+            return obj ? obj->textureSize() : QSize();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::setTextureSize
+    pub fn set_texture_size(&self, size: QSize) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *", size as "QSize"] {
+            if (obj) obj->setTextureSize(size);
+        });
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::performanceHints
+    pub fn performance_hints(&self) -> PerformanceHints {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *"] -> PerformanceHints as "int" {
+            return obj ? int(obj->performanceHints()) : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QQuickPaintedItem::setPerformanceHint
+    pub fn set_performance_hint(&self, hint: PerformanceHints, enabled: bool) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QQuickPaintedItem *", hint as "int", enabled as "bool"] {
+            if (obj) obj->setPerformanceHint(QQuickPaintedItem::PerformanceHint(hint), enabled);
+        });
+    }
+}
+
+/// Bindings for [`QQuickPaintedItem::RenderTarget`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qquickpainteditem.html#RenderTarget-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RenderTarget {
+    Image = 0,
+    FramebufferObject = 1,
+    InvertedYFramebufferObject = 2,
+}
+
+impl Default for RenderTarget {
+    fn default() -> Self {
+        RenderTarget::Image
+    }
+}
+
+/// Bindings for [`QQuickPaintedItem::PerformanceHints`][enum] flags.
+///
+/// [enum]: https://doc.qt.io/qt-5/qquickpainteditem.html#PerformanceHint-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PerformanceHints(pub i32);
+
+impl PerformanceHints {
+    pub const FAST_FBO_RESIZING: PerformanceHints = PerformanceHints(0x1);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: PerformanceHints) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
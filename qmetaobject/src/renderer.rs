@@ -0,0 +1,335 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Headless rendering of QML scenes to images, for golden-image tests.
+
+use std::path::Path;
+use std::time::Duration;
+
+use cpp::{cpp, cpp_class};
+
+use super::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QAnimationDriver>
+    #include <QtCore/QCoreApplication>
+    #include <QtCore/QPointer>
+    #include <QtQuick/QQuickItem>
+    #include <QtQuick/QQuickWindow>
+    #if QT_VERSION >= QT_VERSION_CHECK(5, 8, 0)
+    #include <QtQuick/QSGRendererInterface>
+    #endif
+
+    // Animation driver which only advances when asked to, so animations are deterministic
+    struct RustAnimationDriver : QAnimationDriver {
+        qint64 time = 0;
+
+        void advanceBy(qint64 ms) {
+            time += ms;
+            advance();
+        }
+
+        qint64 elapsed() const override { return time; }
+    };
+
+    struct QmlRendererHolder {
+        std::unique_ptr<QQuickWindow> window;
+        std::unique_ptr<RustAnimationDriver> driver;
+        QPointer<QQuickItem> root;
+
+        QmlRendererHolder(int width, int height)
+            : window(new QQuickWindow), driver(new RustAnimationDriver)
+        {
+            window->resize(width, height);
+            // The software render loop only renders exposed windows. With the offscreen
+            // platform, nothing is shown on screen.
+            window->show();
+            driver->install();
+        }
+
+        ~QmlRendererHolder() {
+            if (driver)
+                driver->uninstall();
+            delete root.data();
+        }
+    };
+}}
+
+cpp_class!(
+    unsafe struct QmlRendererHolder as "QmlRendererHolder"
+);
+
+/// How much a rendered image may differ from the reference, see `compare_images`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImageTolerance {
+    /// Maximum difference of a color channel (red, green, blue or alpha) for a pixel to be
+    /// considered equal.
+    pub max_channel_difference: u8,
+    /// Number of pixels which may differ by more than `max_channel_difference`.
+    pub max_differing_pixels: u32,
+}
+
+/// Error returned by `compare_images` and `compare_with_reference`
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImageMismatch {
+    /// The reference image could not be read.
+    MissingReference(String),
+    SizeMismatch {
+        actual: QSize,
+        expected: QSize,
+    },
+    PixelMismatch {
+        differing_pixels: u32,
+        max_channel_difference: u8,
+    },
+}
+
+impl std::fmt::Display for ImageMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ImageMismatch::MissingReference(path) => {
+                write!(f, "cannot load the reference image {}", path)
+            }
+            ImageMismatch::SizeMismatch { actual, expected } => write!(
+                f,
+                "the image size is {}x{}, expected {}x{}",
+                actual.width, actual.height, expected.width, expected.height
+            ),
+            ImageMismatch::PixelMismatch { differing_pixels, max_channel_difference } => write!(
+                f,
+                "{} pixels differ from the reference (maximum channel difference: {})",
+                differing_pixels, max_channel_difference
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ImageMismatch {}
+
+/// Compares two images pixel by pixel
+pub fn compare_images(
+    actual: &QImage,
+    expected: &QImage,
+    tolerance: ImageTolerance,
+) -> Result<(), ImageMismatch> {
+    if actual.size() != expected.size() {
+        return Err(ImageMismatch::SizeMismatch {
+            actual: actual.size(),
+            expected: expected.size(),
+        });
+    }
+    let threshold = tolerance.max_channel_difference as i32;
+    let mut max_difference: i32 = 0;
+    let max_difference_ptr = &mut max_difference;
+    let differing_pixels = cpp!(unsafe [
+        actual as "const QImage *",
+        expected as "const QImage *",
+        threshold as "int",
+        max_difference_ptr as "int *"
+    ] -> u32 as "uint" {
+        QImage a = actual->convertToFormat(QImage::Format_ARGB32);
+        QImage b = expected->convertToFormat(QImage::Format_ARGB32);
+        uint count = 0;
+        for (int y = 0; y < a.height(); ++y) {
+            auto la = reinterpret_cast<const QRgb *>(a.constScanLine(y));
+            auto lb = reinterpret_cast<const QRgb *>(b.constScanLine(y));
+            for (int x = 0; x < a.width(); ++x) {
+                int diff = qMax(qMax(qAbs(qRed(la[x]) - qRed(lb[x])), qAbs(qGreen(la[x]) - qGreen(lb[x]))),
+                                qMax(qAbs(qBlue(la[x]) - qBlue(lb[x])), qAbs(qAlpha(la[x]) - qAlpha(lb[x]))));
+                *max_difference_ptr = qMax(*max_difference_ptr, diff);
+                if (diff > threshold)
+                    count++;
+            }
+        }
+        return count;
+    });
+    if differing_pixels > tolerance.max_differing_pixels {
+        Err(ImageMismatch::PixelMismatch {
+            differing_pixels,
+            max_channel_difference: max_difference as u8,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Compares an image with a reference PNG file
+///
+/// If the reference is missing or does not match, the image is written next to it, with the
+/// `.actual.png` extension, so it can be inspected or used as the new reference.
+pub fn compare_with_reference<P: AsRef<Path>>(
+    image: &QImage,
+    reference: P,
+    tolerance: ImageTolerance,
+) -> Result<(), ImageMismatch> {
+    let reference = reference.as_ref();
+    let reference_path = QString::from(reference.to_string_lossy().as_ref());
+    let expected = QImage::load_from_file(reference_path);
+    let result = if expected.size() == QSize::default() {
+        Err(ImageMismatch::MissingReference(reference.display().to_string()))
+    } else {
+        compare_images(image, &expected, tolerance)
+    };
+    if result.is_err() {
+        let actual_path =
+            QString::from(reference.with_extension("actual.png").to_string_lossy().as_ref());
+        cpp!(unsafe [image as "const QImage *", actual_path as "QString"] {
+            image->save(actual_path, "PNG");
+        });
+    }
+    result
+}
+
+/// Renders QML scenes to images without display, using the software scene graph backend
+///
+/// This creates a QmlEngine, so there can only be one QmlRenderer or QmlEngine at the same time.
+/// Its application uses the offscreen platform, unless the `QT_QPA_PLATFORM` environment
+/// variable is set.
+///
+/// The software backend is selected for the whole process and is not reset when the renderer is
+/// dropped: engines created later with `QmlEngine::new` also render in software.
+///
+/// The animations only advance when `advance` is called. Timers still use the real time.
+///
+/// ```no_run
+/// use qmetaobject::*;
+/// let mut renderer = QmlRenderer::new(QSize { width: 200, height: 100 });
+/// renderer.load_file("tests/scene.qml".into()).unwrap();
+/// renderer.advance(std::time::Duration::from_millis(500));
+/// let image = renderer.render();
+/// let tolerance = ImageTolerance { max_channel_difference: 2, max_differing_pixels: 0 };
+/// compare_with_reference(&image, "tests/scene.png", tolerance).unwrap();
+/// ```
+pub struct QmlRenderer {
+    // Must be dropped before the engine
+    holder: QmlRendererHolder,
+    engine: QmlEngine,
+}
+
+impl QmlRenderer {
+    /// Creates the engine and an offscreen window of the given size
+    pub fn new(size: QSize) -> QmlRenderer {
+        let engine = QmlEngine::new_offscreen();
+        let width = size.width as i32;
+        let height = size.height as i32;
+        let holder = cpp!(unsafe [width as "int", height as "int"] -> QmlRendererHolder as "QmlRendererHolder" {
+            return QmlRendererHolder(width, height);
+        });
+        QmlRenderer { holder, engine }
+    }
+
+    /// Returns the engine, for example to set context properties before loading the scene
+    pub fn engine(&mut self) -> &mut QmlEngine {
+        &mut self.engine
+    }
+
+    /// Loads the QML file, whose root object must be an Item, replacing the current scene
+    pub fn load_file(&mut self, path: QString) -> Result<(), Vec<QmlError>> {
+        let url = cpp!(unsafe [path as "QString"] -> QUrl as "QUrl" {
+            return QUrl::fromLocalFile(path);
+        });
+        self.load_url(url)
+    }
+
+    /// Loads the QML file at the given URL, replacing the current scene
+    pub fn load_url(&mut self, url: QUrl) -> Result<(), Vec<QmlError>> {
+        let mut component = QmlComponent::new(&self.engine);
+        component.load_url(url, CompilationMode::PreferSynchronous);
+        self.set_root(component.create()?)
+    }
+
+    /// Loads the QML code, replacing the current scene
+    pub fn load_data(&mut self, data: QByteArray) -> Result<(), Vec<QmlError>> {
+        let mut component = QmlComponent::new(&self.engine);
+        component.set_data(data);
+        self.set_root(component.create()?)
+    }
+
+    fn set_root(&mut self, obj: *mut c_void) -> Result<(), Vec<QmlError>> {
+        let holder = &mut self.holder;
+        let is_item = cpp!(unsafe [holder as "QmlRendererHolder *", obj as "QObject *"] -> bool as "bool" {
+            auto item = qobject_cast<QQuickItem *>(obj);
+            if (!item) {
+                delete obj;
+                return false;
+            }
+            delete holder->root.data();
+            holder->root = item;
+            item->setParentItem(holder->window->contentItem());
+            item->setSize(holder->window->size());
+            return true;
+        });
+        if is_item {
+            Ok(())
+        } else {
+            Err(vec![QmlError {
+                url: QString::default(),
+                line: 0,
+                column: 0,
+                description: "The root object is not an Item".into(),
+                message_type: QtMsgType::QtCriticalMsg,
+            }])
+        }
+    }
+
+    /// Returns the root item of the scene
+    pub fn root_object(&self) -> Option<QObjectRef> {
+        let holder = &self.holder;
+        let obj = cpp!(unsafe [holder as "const QmlRendererHolder *"] -> *mut c_void as "QObject *" {
+            return holder->root.data();
+        });
+        if obj.is_null() {
+            None
+        } else {
+            Some(unsafe { QObjectRef::from_cpp_ptr(obj) })
+        }
+    }
+
+    /// Resizes the window and the root item
+    pub fn resize(&mut self, size: QSize) {
+        let holder = &mut self.holder;
+        cpp!(unsafe [holder as "QmlRendererHolder *", size as "QSize"] {
+            holder->window->resize(size);
+            if (holder->root)
+                holder->root->setSize(size);
+        })
+    }
+
+    /// Advances the animations by the given duration, in steps of 16ms (one frame at 60Hz),
+    /// processing the events after every step
+    pub fn advance(&mut self, duration: Duration) {
+        let holder = &mut self.holder;
+        let ms = duration.as_millis() as i64;
+        cpp!(unsafe [holder as "QmlRendererHolder *", ms as "qint64"] {
+            const qint64 frame = 16;
+            for (qint64 t = 0; t < ms; t += frame) {
+                holder->driver->advanceBy(qMin(frame, ms - t));
+                QCoreApplication::processEvents();
+            }
+        })
+    }
+
+    /// Processes the pending events and renders the scene
+    pub fn render(&mut self) -> QImage {
+        let holder = &mut self.holder;
+        cpp!(unsafe [holder as "QmlRendererHolder *"] -> QImage as "QImage" {
+            QCoreApplication::processEvents();
+            return holder->window->grabWindow();
+        })
+    }
+}
@@ -397,6 +397,195 @@ impl SGNode<RectangleNode> {
     }
 }
 
+cpp! {{
+    #include <QtQuick/QQuickWindow>
+    #if QT_VERSION >= QT_VERSION_CHECK(5, 8, 0)
+    #include <QtQuick/QSGImageNode>
+    #else
+    // Just a stub for compatibility
+    struct QSGImageNode{};
+    #endif
+}}
+
+/// Bindings for [`QSGTexture::Filtering`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qsgtexture.html#Filtering-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextureFiltering {
+    None = 0,
+    Nearest = 1,
+    Linear = 2,
+}
+
+#[cfg(qt_5_8)]
+/// Wrapper around QSGImageNode, which draws a texture created from a QImage
+pub enum ImageNode {}
+
+#[cfg(qt_5_8)]
+impl SGNode<ImageNode> {
+    /// Creates the node, if it was not yet created (see QQuickWindow::createImageNode)
+    ///
+    /// Does nothing if the item is not in a window.
+    pub fn create(&mut self, item: &dyn QQuickItem) {
+        if !self.raw.is_null() {
+            return;
+        }
+        let item = item.get_cpp_object();
+        self.raw = cpp!(unsafe [item as "QQuickItem*"] -> *mut c_void as "void*" {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 8, 0)
+            if (!item) return nullptr;
+            if (auto window = item->window())
+                return window->createImageNode();
+            #endif
+            return nullptr;
+        });
+    }
+
+    /// Replaces the texture by a texture created from the image
+    /// (see QQuickWindow::createTextureFromImage)
+    ///
+    /// The source rect is reset to the whole image.
+    pub fn set_image(&mut self, item: &dyn QQuickItem, image: &QImage) {
+        let raw = self.raw;
+        let item = item.get_cpp_object();
+        cpp!(unsafe [raw as "QSGImageNode*", item as "QQuickItem*", image as "const QImage*"] {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 8, 0)
+            if (!raw || !item || !item->window())
+                return;
+            // Deletes the previous texture
+            raw->setOwnsTexture(true);
+            raw->setTexture(item->window()->createTextureFromImage(*image));
+            raw->setSourceRect(QRectF(QPointF(), image->size()));
+            #endif
+        });
+    }
+
+    /// Sets the rectangle in which the texture is drawn, in the coordinates of the item
+    pub fn set_rect(&mut self, rect: QRectF) {
+        let raw = self.raw;
+        cpp!(unsafe [raw as "QSGImageNode*", rect as "QRectF"] {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 8, 0)
+            if (raw) raw->setRect(rect);
+            #endif
+        });
+    }
+
+    /// Sets the part of the image which is drawn, in pixels
+    pub fn set_source_rect(&mut self, rect: QRectF) {
+        let raw = self.raw;
+        cpp!(unsafe [raw as "QSGImageNode*", rect as "QRectF"] {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 8, 0)
+            if (raw) raw->setSourceRect(rect);
+            #endif
+        });
+    }
+
+    /// Sets how the texture is sampled when it is scaled (see QSGImageNode::setFiltering)
+    pub fn set_filtering(&mut self, filtering: TextureFiltering) {
+        let raw = self.raw;
+        cpp!(unsafe [raw as "QSGImageNode*", filtering as "int"] {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 8, 0)
+            if (raw) raw->setFiltering(QSGTexture::Filtering(filtering));
+            #endif
+        });
+    }
+}
+
+cpp! {{
+    #if QT_VERSION >= QT_VERSION_CHECK(6, 7, 0)
+    #include <QtQuick/QSGTextNode>
+    #include <QtGui/QFontMetricsF>
+    #include <QtGui/QTextLayout>
+    #else
+    // Just a stub for compatibility
+    struct QSGTextNode{};
+    #endif
+}}
+
+#[cfg(qt_6_7)]
+/// Wrapper around QSGTextNode, which draws a text in a rectangle
+///
+/// Requires Qt 6.7 or later.
+pub enum TextNode {}
+
+#[cfg(qt_6_7)]
+impl SGNode<TextNode> {
+    /// Creates the node, if it was not yet created (see QQuickWindow::createTextNode)
+    ///
+    /// Does nothing if the item is not in a window.
+    pub fn create(&mut self, item: &dyn QQuickItem) {
+        if !self.raw.is_null() {
+            return;
+        }
+        let item = item.get_cpp_object();
+        self.raw = cpp!(unsafe [item as "QQuickItem*"] -> *mut c_void as "void*" {
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 7, 0)
+            if (!item) return nullptr;
+            if (auto window = item->window())
+                return window->createTextNode();
+            #endif
+            return nullptr;
+        });
+    }
+
+    /// Replaces the text drawn by the node
+    ///
+    /// The text is laid out in `rect` with the given alignment. With `TextElideMode::ElideNone`,
+    /// the text is wrapped at word boundaries, otherwise it is drawn on one line and elided
+    /// if it is wider than `rect`. The text is not clipped to `rect`.
+    pub fn set_text(
+        &mut self,
+        text: QString,
+        font: &QFont,
+        color: QColor,
+        rect: QRectF,
+        alignment: Alignment,
+        elide: TextElideMode,
+    ) {
+        let raw = self.raw;
+        cpp!(unsafe [
+            raw as "QSGTextNode*",
+            text as "QString",
+            font as "const QFont*",
+            color as "QColor",
+            rect as "QRectF",
+            alignment as "int",
+            elide as "Qt::TextElideMode"
+        ] {
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 7, 0)
+            if (!raw)
+                return;
+            raw->clear();
+            raw->setColor(color);
+            raw->setViewport(rect);
+
+            QString shown = elide == Qt::ElideNone
+                ? text : QFontMetricsF(*font).elidedText(text, elide, rect.width());
+            QTextLayout layout(shown, *font);
+            QTextOption option(Qt::Alignment(alignment) & Qt::AlignHorizontal_Mask);
+            option.setWrapMode(elide == Qt::ElideNone ? QTextOption::WordWrap : QTextOption::NoWrap);
+            layout.setTextOption(option);
+            qreal height = 0;
+            layout.beginLayout();
+            for (QTextLine line = layout.createLine(); line.isValid(); line = layout.createLine()) {
+                line.setLineWidth(rect.width());
+                line.setPosition(QPointF(0, height));
+                height += line.height();
+            }
+            layout.endLayout();
+
+            QPointF position = rect.topLeft();
+            if (alignment & Qt::AlignBottom)
+                position.ry() += rect.height() - height;
+            else if (alignment & Qt::AlignVCenter)
+                position.ry() += (rect.height() - height) / 2;
+            raw->addTextLayout(position, &layout);
+            #endif
+        });
+    }
+}
+
 /// Wrapper around QSGTransformNode
 pub enum TransformNode {}
 
@@ -422,58 +611,372 @@ impl SGNode<TransformNode> {
 
     pub fn update_sub_node<F: FnMut(SGNode<ContainerNode>) -> SGNode<ContainerNode>>(
         &mut self,
-        mut f: F,
+        f: F,
+    ) {
+        if self.raw.is_null() {
+            self.create();
+        }
+        update_sub_node(self.raw, f);
+    }
+}
+
+/// Runs the update function on the ContainerNode child of `raw`
+fn update_sub_node<F: FnMut(SGNode<ContainerNode>) -> SGNode<ContainerNode>>(
+    raw: *mut c_void,
+    mut f: F,
+) {
+    let sub = unsafe {
+        SGNode::<ContainerNode>::from_raw(cpp!([raw as "QSGNode*"] -> *mut c_void as "QSGNode*" {
+            auto n = raw->firstChild();
+            if (n)
+                n->setFlag(QSGNode::OwnedByParent, false); // now we own it;
+            return n;
+        }))
+    };
+    let sub = f(sub);
+    let node = sub.into_raw();
+    cpp!(unsafe [node as "QSGNode*", raw as "QSGNode*"] {
+        if (!node)
+            return;
+        if (!node->parent()) {
+            raw->prependChildNode(node);
+        } else if (node->parent() != raw) {
+            rust!(sgnode_5 []{ panic!("Returned node from another parent") });
+        }
+        node->setFlag(QSGNode::OwnedByParent);
+    });
+}
+
+cpp! {{
+    #include <QtQuick/QSGOpacityNode>
+    #include <QtQuick/QSGClipNode>
+    #include <QtQuick/QSGGeometry>
+}}
+
+/// Wrapper around QSGOpacityNode
+///
+/// The opacity applies to the sub node.
+pub enum OpacityNode {}
+
+impl SGNode<OpacityNode> {
+    pub fn create(&mut self) {
+        if !self.raw.is_null() {
+            return;
+        }
+        self.raw = cpp!(unsafe [] -> *mut c_void as "void*" { return new QSGOpacityNode; });
+    }
+
+    /// Sets the opacity, between 0 and 1 (see QSGOpacityNode::setOpacity)
+    pub fn set_opacity(&mut self, opacity: f64) {
+        if self.raw.is_null() {
+            self.create();
+        }
+        let raw = self.raw;
+        cpp!(unsafe [raw as "QSGOpacityNode*", opacity as "qreal"] {
+            raw->setOpacity(opacity);
+        });
+    }
+
+    pub fn update_sub_node<F: FnMut(SGNode<ContainerNode>) -> SGNode<ContainerNode>>(
+        &mut self,
+        f: F,
     ) {
+        if self.raw.is_null() {
+            self.create();
+        }
+        update_sub_node(self.raw, f);
+    }
+}
+
+/// Wrapper around QSGClipNode, clipping the sub node to a rectangle
+pub enum ClipNode {}
+
+impl SGNode<ClipNode> {
+    pub fn create(&mut self) {
+        if !self.raw.is_null() {
+            return;
+        }
+        self.raw = cpp!(unsafe [] -> *mut c_void as "void*" {
+            auto node = new QSGClipNode;
+            // The geometry is used by the OpenGL renderer, the rectangle by the others
+            node->setGeometry(new QSGGeometry(QSGGeometry::defaultAttributes_Point2D(), 4));
+            node->setFlag(QSGNode::OwnsGeometry);
+            node->setIsRectangular(true);
+            return node;
+        });
+    }
+
+    /// Sets the rectangle, in the coordinates of the item
+    pub fn set_clip_rect(&mut self, rect: QRectF) {
         if self.raw.is_null() {
             self.create();
         }
         let raw = self.raw;
-        let sub = unsafe {
-            SGNode::<ContainerNode>::from_raw(
-                cpp!([raw as "QSGNode*"] -> *mut c_void as "QSGNode*" {
-                    auto n = raw->firstChild();
-                    if (n)
-                        n->setFlag(QSGNode::OwnedByParent, false); // now we own it;
-                    return n;
-                }),
-            )
-        };
-        let sub = f(sub);
-        let node = sub.into_raw();
-        cpp!(unsafe [node as "QSGNode*", raw as "QSGNode*"] {
-            if (!node->parent()) {
-                raw->prependChildNode(node);
-            } else if (node->parent() != raw) {
-                rust!(sgnode_5 []{ panic!("Returned node from another parent") });
-            }
-            node->setFlag(QSGNode::OwnedByParent);
+        cpp!(unsafe [raw as "QSGClipNode*", rect as "QRectF"] {
+            raw->setClipRect(rect);
+            QSGGeometry::updateRectGeometry(raw->geometry(), rect);
+            raw->markDirty(QSGNode::DirtyGeometry);
         });
     }
+
+    pub fn update_sub_node<F: FnMut(SGNode<ContainerNode>) -> SGNode<ContainerNode>>(
+        &mut self,
+        f: F,
+    ) {
+        if self.raw.is_null() {
+            self.create();
+        }
+        update_sub_node(self.raw, f);
+    }
 }
 
-/*
+cpp! {{
+    #include <cstring>
+    #include <QtQuick/QSGGeometryNode>
+    #include <QtQuick/QSGFlatColorMaterial>
+    #include <QtQuick/QSGVertexColorMaterial>
+}}
+
+/// The layout of the vertices of a GeometryNode, see [`QSGGeometry::AttributeSet`][class]
+///
+/// Implemented by Point2D, ColoredPoint2D and TexturedPoint2D. This trait is sealed and cannot
+/// be implemented outside of this crate.
+///
+/// [class]: https://doc.qt.io/qt-5/qsggeometry-attributeset.html
+pub trait Vertex: sealed::Sealed + Copy + 'static {
+    #[doc(hidden)]
+    /// 0 for Point2D, 1 for ColoredPoint2D, 2 for TexturedPoint2D
+    const ATTRIBUTE_SET: i32;
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Point2D {}
+    impl Sealed for super::ColoredPoint2D {}
+    impl Sealed for super::TexturedPoint2D {}
+}
+
+/// A vertex with a position, see [`QSGGeometry::Point2D`][class]
+///
+/// [class]: https://doc.qt.io/qt-5/qsggeometry-point2d.html
 #[repr(C)]
-struct SGGeometryNode {
-    node : SGNode,
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Point2D {
+    pub x: f32,
+    pub y: f32,
 }
 
-cpp! {{
-struct RustGeometryNode : QSGGeometryNode {
-    QSGGeometry geo;
-    RustGeometryNode(const QSGGeometry::AttributeSet &attribs, int vertexCount)
-        : geo(attribs, vertexCount) {
-        setGeometry(&geo);
+impl Vertex for Point2D {
+    const ATTRIBUTE_SET: i32 = 0;
+}
+
+/// A vertex with a position and a color, see [`QSGGeometry::ColoredPoint2D`][class]
+///
+/// The color is premultiplied by the alpha.
+///
+/// [class]: https://doc.qt.io/qt-5/qsggeometry-coloredpoint2d.html
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ColoredPoint2D {
+    pub x: f32,
+    pub y: f32,
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl ColoredPoint2D {
+    /// Creates a vertex with the given color, which is premultiplied
+    pub fn new(x: f32, y: f32, color: QColor) -> Self {
+        let (r, g, b, a) = color.get_rgba_f();
+        let premultiply = |c: qreal| (c * a * 255.).round() as u8;
+        ColoredPoint2D {
+            x,
+            y,
+            r: premultiply(r),
+            g: premultiply(g),
+            b: premultiply(b),
+            a: color.alpha() as u8,
+        }
     }
 }
-}}
 
-impl SGGeometryNode<> {
-    fn create() -> Self {
-        Self { node : SGNode { raw : cpp!(unsafe [] -> c_void as "QSGNode*" {
-            return new RustGeometryNode()
-        })}}
+impl Vertex for ColoredPoint2D {
+    const ATTRIBUTE_SET: i32 = 1;
+}
+
+/// A vertex with a position and texture coordinates, see [`QSGGeometry::TexturedPoint2D`][class]
+///
+/// [class]: https://doc.qt.io/qt-5/qsggeometry-texturedpoint2d.html
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct TexturedPoint2D {
+    pub x: f32,
+    pub y: f32,
+    pub tx: f32,
+    pub ty: f32,
+}
+
+impl Vertex for TexturedPoint2D {
+    const ATTRIBUTE_SET: i32 = 2;
+}
+
+/// How the vertices of a GeometryNode are drawn, see [`QSGGeometry::DrawingMode`][enum]
+///
+/// `LineLoop` and `TriangleFan` are not supported by all the graphics backends of Qt 6.
+///
+/// [enum]: https://doc.qt.io/qt-5/qsggeometry.html#DrawingMode-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DrawingMode {
+    Points = 0,
+    Lines = 1,
+    LineLoop = 2,
+    LineStrip = 3,
+    Triangles = 4,
+    TriangleStrip = 5,
+    TriangleFan = 6,
+}
+
+/// Wrapper around QSGGeometryNode, with vertices of type `V`
+///
+/// The node is drawn with a flat color (QSGFlatColorMaterial), or with the color of the
+/// vertices (QSGVertexColorMaterial) if `V` is ColoredPoint2D.
+///
+/// ```
+/// use qmetaobject::scenegraph::{DrawingMode, GeometryNode, Point2D, SGNode};
+/// use qttypes::QColor;
+///
+/// # struct Dummy<T> { samples: Vec<f32>, _phantom: T }
+/// # impl<T> Dummy<T> {
+/// // called from QQuickItem::update_paint_node
+/// fn update_polyline(&self, mut n: SGNode<GeometryNode<Point2D>>) -> SGNode<GeometryNode<Point2D>> {
+///     n.create(DrawingMode::LineStrip);
+///     n.set_color(QColor::from_name("steelblue"));
+///     let points: Vec<Point2D> = self.samples.iter().enumerate()
+///         .map(|(i, y)| Point2D { x: i as f32 * 10., y: *y })
+///         .collect();
+///     n.set_vertices(&points);
+///     n
+/// }
+/// # }
+/// ```
+pub struct GeometryNode<V: Vertex>(std::convert::Infallible, std::marker::PhantomData<V>);
+
+impl<V: Vertex> SGNode<GeometryNode<V>> {
+    /// Creates the node with no vertices, if it was not yet created
+    pub fn create(&mut self, mode: DrawingMode) {
+        if !self.raw.is_null() {
+            return;
+        }
+        let attribute_set = V::ATTRIBUTE_SET;
+        self.raw = cpp!(unsafe [attribute_set as "int", mode as "unsigned int"] -> *mut c_void as "QSGNode*" {
+            auto node = new QSGGeometryNode;
+            const QSGGeometry::AttributeSet &attributes = attribute_set == 1
+                ? QSGGeometry::defaultAttributes_ColoredPoint2D()
+                : attribute_set == 2 ? QSGGeometry::defaultAttributes_TexturedPoint2D()
+                                     : QSGGeometry::defaultAttributes_Point2D();
+            auto geometry = new QSGGeometry(attributes, 0);
+            geometry->setDrawingMode(mode);
+            node->setGeometry(geometry);
+            node->setFlag(QSGNode::OwnsGeometry);
+            if (attribute_set == 1)
+                node->setMaterial(new QSGVertexColorMaterial);
+            else
+                node->setMaterial(new QSGFlatColorMaterial);
+            node->setFlag(QSGNode::OwnsMaterial);
+            return node;
+        });
+    }
+
+    /// Replaces the vertices, which are drawn in order
+    ///
+    /// Panics if the node was not created.
+    pub fn set_vertices(&mut self, vertices: &[V]) {
+        self.set_indexed_vertices(vertices, &[]);
+    }
+
+    /// Replaces the vertices and the indices of the vertices to draw, for example to share
+    /// the vertices between triangles of a mesh
+    ///
+    /// Panics if the node was not created.
+    pub fn set_indexed_vertices(&mut self, vertices: &[V], indices: &[u16]) {
+        let raw = self.raw;
+        assert!(!raw.is_null(), "GeometryNode::create must be called first");
+        let vertex_size = std::mem::size_of::<V>();
+        let geometry_vertex_size = cpp!(unsafe [raw as "QSGGeometryNode*"] -> usize as "size_t" {
+            return size_t(raw->geometry()->sizeOfVertex());
+        });
+        assert_eq!(
+            vertex_size, geometry_vertex_size,
+            "The vertex type does not match the geometry"
+        );
+        let vertex_count = vertices.len();
+        let vertex_ptr = vertices.as_ptr();
+        let index_count = indices.len();
+        let index_ptr = indices.as_ptr();
+        cpp!(unsafe [
+            raw as "QSGGeometryNode*",
+            vertex_size as "size_t",
+            vertex_count as "size_t",
+            vertex_ptr as "const char*",
+            index_count as "size_t",
+            index_ptr as "const quint16*"
+        ] {
+            QSGGeometry *geometry = raw->geometry();
+            geometry->allocate(int(vertex_count), int(index_count));
+            if (vertex_count)
+                memcpy(geometry->vertexData(), vertex_ptr, vertex_count * vertex_size);
+            if (index_count)
+                memcpy(geometry->indexDataAsUShort(), index_ptr, index_count * sizeof(quint16));
+            raw->markDirty(QSGNode::DirtyGeometry);
+        });
+    }
+
+    /// Refer to the Qt documentation of QSGGeometry::setDrawingMode
+    pub fn set_drawing_mode(&mut self, mode: DrawingMode) {
+        let raw = self.raw;
+        cpp!(unsafe [raw as "QSGGeometryNode*", mode as "unsigned int"] {
+            if (!raw)
+                return;
+            raw->geometry()->setDrawingMode(mode);
+            raw->markDirty(QSGNode::DirtyGeometry);
+        });
     }
-}*/
+
+    /// Sets the width of the lines, in pixels (see QSGGeometry::setLineWidth)
+    ///
+    /// Only a width of 1 is supported by all the graphics backends of Qt 6.
+    pub fn set_line_width(&mut self, width: f32) {
+        let raw = self.raw;
+        cpp!(unsafe [raw as "QSGGeometryNode*", width as "float"] {
+            if (!raw)
+                return;
+            raw->geometry()->setLineWidth(width);
+            raw->markDirty(QSGNode::DirtyGeometry);
+        });
+    }
+
+    /// Sets the color of the flat color material
+    ///
+    /// Has no effect if `V` is ColoredPoint2D.
+    pub fn set_color(&mut self, color: QColor) {
+        if V::ATTRIBUTE_SET == ColoredPoint2D::ATTRIBUTE_SET {
+            return;
+        }
+        let raw = self.raw;
+        cpp!(unsafe [raw as "QSGGeometryNode*", color as "QColor"] {
+            if (!raw)
+                return;
+            auto material = static_cast<QSGFlatColorMaterial *>(raw->material());
+            if (material->color() != color) {
+                material->setColor(color);
+                raw->markDirty(QSGNode::DirtyMaterial);
+            }
+        });
+    }
+}
 
 /*
 
@@ -0,0 +1,242 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use std::cmp::Ordering;
+
+use cpp::cpp;
+
+use super::*;
+
+/// This trait allow to override a Qt QSortFilterProxyModel
+pub trait QSortFilterProxyModel: QObject {
+    /// Required for the implementation detail of the QObject custom derive
+    fn get_object_description() -> &'static QObjectDescriptor
+    where
+        Self: Sized,
+    {
+        unsafe {
+            &*cpp!([]-> *const QObjectDescriptor as "RustQObjectDescriptor const*" {
+                return RustQObjectDescriptor::instance<Rust_QSortFilterProxyModel>();
+            })
+        }
+    }
+
+    /// Refer to the Qt documentation of QSortFilterProxyModel::filterAcceptsRow
+    ///
+    /// The default implementation calls the one from QSortFilterProxyModel.
+    fn filter_accepts_row(&self, source_row: i32, source_parent: QModelIndex) -> bool {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [
+            obj as "Rust_QSortFilterProxyModel *",
+            source_row as "int",
+            source_parent as "QModelIndex"
+        ] -> bool as "bool" {
+            return obj ? obj->baseFilterAcceptsRow(source_row, source_parent) : true;
+        })
+    }
+
+    /// Refer to the Qt documentation of QSortFilterProxyModel::lessThan
+    ///
+    /// The default implementation calls the one from QSortFilterProxyModel.
+    fn less_than(&self, source_left: QModelIndex, source_right: QModelIndex) -> bool {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [
+            obj as "Rust_QSortFilterProxyModel *",
+            source_left as "QModelIndex",
+            source_right as "QModelIndex"
+        ] -> bool as "bool" {
+            return obj ? obj->baseLessThan(source_left, source_right) : false;
+        })
+    }
+
+    /// Refer to the Qt documentation of QSortFilterProxyModel::invalidateFilter
+    fn invalidate_filter(&self) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QSortFilterProxyModel *"] {
+            if (obj) obj->invalidateFilter();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSortFilterProxyModel::invalidate
+    fn invalidate(&self) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QSortFilterProxyModel *"] {
+            if (obj) obj->invalidate();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSortFilterProxyModel::sort
+    fn sort(&self, column: i32, order: SortOrder) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QSortFilterProxyModel *", column as "int", order as "Qt::SortOrder"] {
+            if (obj) obj->sort(column, order);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSortFilterProxyModel::setDynamicSortFilter
+    fn set_dynamic_sort_filter(&self, enable: bool) {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QSortFilterProxyModel *", enable as "bool"] {
+            if (obj) obj->setDynamicSortFilter(enable);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSortFilterProxyModel::mapToSource
+    fn map_to_source(&self, proxy_index: QModelIndex) -> QModelIndex {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QSortFilterProxyModel *", proxy_index as "QModelIndex"] -> QModelIndex as "QModelIndex" {
+            return obj ? obj->mapToSource(proxy_index) : QModelIndex();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSortFilterProxyModel::mapFromSource
+    fn map_from_source(&self, source_index: QModelIndex) -> QModelIndex {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QSortFilterProxyModel *", source_index as "QModelIndex"] -> QModelIndex as "QModelIndex" {
+            return obj ? obj->mapFromSource(source_index) : QModelIndex();
+        })
+    }
+}
+
+cpp! {{
+    #include <qmetaobject_rust.hpp>
+    #include <QtCore/QSortFilterProxyModel>
+
+    struct Rust_QSortFilterProxyModel : RustObject<QSortFilterProxyModel> {
+
+        using QSortFilterProxyModel::invalidateFilter;
+
+        bool filterAcceptsRow(int source_row, const QModelIndex &source_parent) const override {
+            return rust!(Rust_QSortFilterProxyModel_filterAcceptsRow[rust_object : QObjectPinned<dyn QSortFilterProxyModel> as "TraitObject",
+                    source_row : i32 as "int", source_parent : QModelIndex as "QModelIndex"] -> bool as "bool" {
+                rust_object.borrow().filter_accepts_row(source_row, source_parent)
+            });
+        }
+
+        bool lessThan(const QModelIndex &source_left, const QModelIndex &source_right) const override {
+            return rust!(Rust_QSortFilterProxyModel_lessThan[rust_object : QObjectPinned<dyn QSortFilterProxyModel> as "TraitObject",
+                    source_left : QModelIndex as "QModelIndex", source_right : QModelIndex as "QModelIndex"] -> bool as "bool" {
+                rust_object.borrow().less_than(source_left, source_right)
+            });
+        }
+
+        bool baseFilterAcceptsRow(int source_row, const QModelIndex &source_parent) const {
+            return QSortFilterProxyModel::filterAcceptsRow(source_row, source_parent);
+        }
+
+        bool baseLessThan(const QModelIndex &source_left, const QModelIndex &source_right) const {
+            return QSortFilterProxyModel::lessThan(source_left, source_right);
+        }
+    };
+}}
+
+/// A QSortFilterProxyModel which filters and sorts the rows of another model
+/// implemented in Rust using closures.
+///
+/// The source can be any `QObject` implementing one of the model traits of this crate
+/// (`QAbstractItemModel`, `QAbstractListModel`, `QAbstractTableModel`, or a `SimpleListModel`).
+/// The filter and the comparator are given the row in the source model and a reference to
+/// the source model.
+/// For models with children, only the top level rows are passed to the filter, the children
+/// are always accepted.
+///
+/// Dynamic sorting and filtering is enabled, so rows inserted in or removed from the source
+/// model are filtered and sorted automatically. Call `invalidate_filter` when the result of the
+/// filter changes for a reason not known to the source model (e.g. the search string changed).
+#[derive(QObject)]
+// This is a bit weird because the rules are different as we are in the qmetaobject crate
+#[QMetaObjectCrate = "super"]
+pub struct SortFilterProxyModel<M: QObject + 'static> {
+    #[qt_base_class = "QSortFilterProxyModel"]
+    base: QObjectCppWrapper,
+    source: QPointer<M>,
+    filter: Option<Box<dyn Fn(i32, &M) -> bool>>,
+    compare: Option<Box<dyn Fn(i32, i32, &M) -> Ordering>>,
+}
+
+impl<M: QObject> Default for SortFilterProxyModel<M> {
+    fn default() -> Self {
+        SortFilterProxyModel {
+            base: Default::default(),
+            source: Default::default(),
+            filter: None,
+            compare: None,
+        }
+    }
+}
+
+impl<M: QObject> QSortFilterProxyModel for SortFilterProxyModel<M> {
+    fn filter_accepts_row(&self, source_row: i32, source_parent: QModelIndex) -> bool {
+        match (&self.filter, self.source.as_ref()) {
+            (Some(filter), Some(source)) if !source_parent.is_valid() => filter(source_row, source),
+            _ => true,
+        }
+    }
+
+    fn less_than(&self, source_left: QModelIndex, source_right: QModelIndex) -> bool {
+        match (&self.compare, self.source.as_ref()) {
+            (Some(compare), Some(source)) => {
+                compare(source_left.row(), source_right.row(), source) == Ordering::Less
+            }
+            _ => source_left.row() < source_right.row(),
+        }
+    }
+}
+
+impl<M: QObject> SortFilterProxyModel<M> {
+    /// Sets the model to be filtered and sorted (see QSortFilterProxyModel::setSourceModel)
+    ///
+    /// The C++ object of the source model is created if needed, but the one of this proxy model
+    /// must already have been created.
+    pub fn set_source_model(&mut self, source: QObjectPinned<M>) {
+        let source_ptr = source.get_or_create_cpp_object();
+        self.source = QPointer::from(source.borrow());
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "QSortFilterProxyModel *", source_ptr as "QObject *"] {
+            if (obj) obj->setSourceModel(qobject_cast<QAbstractItemModel *>(source_ptr));
+        });
+    }
+
+    /// Returns the source model, or None if none was set or if it was destroyed
+    pub fn source_model(&self) -> Option<&M> {
+        self.source.as_ref()
+    }
+
+    /// Sets the filter deciding which rows of the source model are shown, and re-filter the model.
+    pub fn set_filter<F: Fn(i32, &M) -> bool + 'static>(&mut self, filter: F) {
+        self.filter = Some(Box::new(filter));
+        (self as &dyn QSortFilterProxyModel).invalidate_filter();
+    }
+
+    /// Removes the filter so all the rows of the source model are shown.
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+        (self as &dyn QSortFilterProxyModel).invalidate_filter();
+    }
+
+    /// Sets the comparator used to sort the rows of the source model, and sort the model.
+    pub fn set_comparator<F: Fn(i32, i32, &M) -> Ordering + 'static>(&mut self, compare: F) {
+        self.compare = Some(Box::new(compare));
+        (self as &dyn QSortFilterProxyModel).sort(0, SortOrder::AscendingOrder);
+    }
+
+    /// Removes the comparator so the rows are shown in the same order as in the source model.
+    pub fn clear_comparator(&mut self) {
+        self.compare = None;
+        (self as &dyn QSortFilterProxyModel).sort(-1, SortOrder::AscendingOrder);
+    }
+}
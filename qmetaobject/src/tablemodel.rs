@@ -29,6 +29,32 @@ pub trait QAbstractTableModel: QObject {
     fn role_names(&self) -> HashMap<i32, QByteArray> {
         HashMap::new()
     }
+    /// Refer to the Qt documentation of QAbstractTableModel::canFetchMore
+    fn can_fetch_more(&self, _parent: QModelIndex) -> bool {
+        false
+    }
+    /// Refer to the Qt documentation of QAbstractTableModel::fetchMore
+    fn fetch_more(&mut self, _parent: QModelIndex) {}
+    /// Refer to the Qt documentation of QAbstractTableModel::headerData
+    ///
+    /// The default implementation calls the one from QAbstractTableModel.
+    fn header_data(&self, section: i32, orientation: Orientation, role: i32) -> QVariant {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QAbstractTableModel*", section as "int", orientation as "Qt::Orientation", role as "int"] -> QVariant as "QVariant" {
+            return obj ? obj->baseHeaderData(section, orientation, role) : QVariant();
+        })
+    }
+    /// Refer to the Qt documentation of QAbstractTableModel::flags
+    ///
+    /// The default implementation calls the one from QAbstractTableModel.
+    fn flags(&self, index: QModelIndex) -> ItemFlags {
+        let obj = self.get_cpp_object();
+        cpp!(unsafe [obj as "Rust_QAbstractTableModel*", index as "QModelIndex"] -> ItemFlags as "Qt::ItemFlags" {
+            return obj ? obj->baseFlags(index) : Qt::ItemFlags();
+        })
+    }
+    /// Refer to the Qt documentation of QAbstractTableModel::sort
+    fn sort(&mut self, _column: i32, _order: SortOrder) {}
 
     /// Refer to the Qt documentation of QAbstractItemModel::beginInsertRows
     fn begin_insert_rows(&mut self, first: i32, last: i32) {
@@ -133,6 +159,15 @@ pub trait QAbstractTableModel: QObject {
             })
         }
     }
+    /// Refer to the Qt documentation of QAbstractItemModel::headerDataChanged
+    fn header_data_changed(&mut self, orientation: Orientation, first: i32, last: i32) {
+        let obj = self.get_cpp_object();
+        unsafe {
+            cpp!([obj as "Rust_QAbstractTableModel*", orientation as "Qt::Orientation", first as "int", last as "int"]{
+                if(obj) obj->headerDataChanged(orientation, first, last);
+            })
+        }
+    }
     /// Returns a QModelIndex for the given row and column
     fn index(&self, row: i32, col: i32) -> QModelIndex {
         let obj = self.get_cpp_object();
@@ -190,9 +225,35 @@ cpp! {{
             });
         }
 
-        //Qt::ItemFlags flags(const QModelIndex &index) const override;
+        Qt::ItemFlags flags(const QModelIndex &index) const override {
+            return rust!(Rust_QAbstractTableModel_flags[rust_object : QObjectPinned<dyn QAbstractTableModel> as "TraitObject",
+                    index : QModelIndex as "QModelIndex"] -> ItemFlags as "Qt::ItemFlags" {
+                rust_object.borrow().flags(index)
+            });
+        }
+
+        QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override {
+            return rust!(Rust_QAbstractTableModel_headerData[rust_object : QObjectPinned<dyn QAbstractTableModel> as "TraitObject",
+                    section : i32 as "int", orientation : Orientation as "Qt::Orientation", role : i32 as "int"]
+                    -> QVariant as "QVariant" {
+                rust_object.borrow().header_data(section, orientation, role)
+            });
+        }
+
+        void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override {
+            rust!(Rust_QAbstractTableModel_sort[rust_object : QObjectPinned<dyn QAbstractTableModel> as "TraitObject",
+                    column : i32 as "int", order : SortOrder as "Qt::SortOrder"] {
+                rust_object.borrow_mut().sort(column, order)
+            });
+        }
+
+        QVariant baseHeaderData(int section, Qt::Orientation orientation, int role) const {
+            return QAbstractTableModel::headerData(section, orientation, role);
+        }
 
-        //QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override;
+        Qt::ItemFlags baseFlags(const QModelIndex &index) const {
+            return QAbstractTableModel::flags(index);
+        }
 
         QHash<int, QByteArray> roleNames() const override {
             QHash<int, QByteArray> base = QAbstractTableModel::roleNames();
@@ -205,6 +266,20 @@ cpp! {{
             return base;
         }
 
+        bool canFetchMore(const QModelIndex &parent) const override {
+            return rust!(Rust_QAbstractTableModel_canFetchMore[rust_object : QObjectPinned<dyn QAbstractTableModel> as "TraitObject",
+                    parent : QModelIndex as "QModelIndex"] -> bool as "bool" {
+                rust_object.borrow().can_fetch_more(parent)
+            });
+        }
+
+        void fetchMore(const QModelIndex &parent) override {
+            rust!(Rust_QAbstractTableModel_fetchMore[rust_object : QObjectPinned<dyn QAbstractTableModel> as "TraitObject",
+                    parent : QModelIndex as "QModelIndex"] {
+                rust_object.borrow_mut().fetch_more(parent)
+            });
+        }
+
         //QModelIndex index(int row, int column, const QModelIndex &parent) const override;
 
         //QModelIndex parent(const QModelIndex &child) const override;
@@ -0,0 +1,162 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Helpers to write tests for the models implemented in Rust.
+//!
+//! This module requires the `qttest` feature, and Qt 5.11 or later.
+
+use std::sync::Mutex;
+
+use cpp::cpp;
+
+use super::*;
+
+cpp! {{
+    #include <QtCore/QAbstractItemModel>
+    #if QT_VERSION >= QT_VERSION_CHECK(5, 11, 0)
+    #include <QtTest/QAbstractItemModelTester>
+    #endif
+}}
+
+/// Checks the consistency of a model with [`QAbstractItemModelTester`][class].
+///
+/// The model can be any `QObject` implementing one of the model traits of this crate
+/// (`QAbstractItemModel`, `QAbstractListModel`, `QAbstractTableModel`), or one of the models
+/// provided by this crate. The C++ object of the model is created if needed.
+///
+/// The model is checked right away, then `f` is called and the model is checked again every time
+/// it emits a signal until `f` returns. So `f` should contain the code modifying the model, which
+/// is also checked (e.g. missing `begin_insert_rows`, or wrong parent indexes).
+///
+/// The messages of the tester are collected while the model is checked, and this function panics
+/// with them if any check failed, which makes the current test fail. The other messages are
+/// passed to the message handler that was installed before, which is restored afterwards.
+///
+/// The tester uses the `Warning` reporting mode rather than the `Fatal` one: a fatal failure
+/// aborts the process, before the panic could report which model and which step failed, and
+/// without running the other tests.
+///
+/// The message handler is global to the process, so the calls from different threads must be
+/// serialised, like the tests using Qt usually are. Nested calls, to check several models at
+/// once, are supported.
+///
+/// Panics if the object is not a model.
+///
+/// [class]: https://doc.qt.io/qt-5/qabstractitemmodeltester.html
+pub fn check_model<T: QObject, R>(model: QObjectPinned<T>, f: impl FnOnce() -> R) -> R {
+    let obj = model.get_or_create_cpp_object();
+    let is_model = cpp!(unsafe [obj as "QObject *"] -> bool as "bool" {
+        return qobject_cast<QAbstractItemModel *>(obj) != nullptr;
+    });
+    assert!(is_model, "check_model: the object is not a QAbstractItemModel");
+
+    let mut guard = TesterGuard::new();
+    guard.tester = cpp!(unsafe [obj as "QObject *"] -> *mut c_void as "QObject *" {
+    #if QT_VERSION >= QT_VERSION_CHECK(5, 11, 0)
+        auto model = qobject_cast<QAbstractItemModel *>(obj);
+        return new QAbstractItemModelTester(
+            model, QAbstractItemModelTester::FailureReportingMode::Warning);
+    #else
+        Q_UNUSED(obj);
+        return nullptr;
+    #endif
+    });
+    let result = f();
+    let failures = guard.finish();
+    if !failures.is_empty() {
+        panic!("check_model: the model is inconsistent:\n{}", failures.join("\n"));
+    }
+    result
+}
+
+/// The message handler is installed by the outermost `check_model`, nested calls share it.
+struct TesterState {
+    depth: usize,
+    previous: QtMessageHandler,
+    messages: Vec<String>,
+}
+
+lazy_static! {
+    static ref TESTER_STATE: Mutex<TesterState> =
+        Mutex::new(TesterState { depth: 0, previous: None, messages: Vec::new() });
+}
+
+fn tester_state() -> std::sync::MutexGuard<'static, TesterState> {
+    TESTER_STATE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+const MODEL_TESTER_CATEGORY: &str = "qt.modeltest";
+
+extern "C" fn tester_message_handler(
+    msg_type: QtMsgType,
+    context: &QMessageLogContext,
+    message: &QString,
+) {
+    let previous = {
+        let mut state = tester_state();
+        if context.category() == MODEL_TESTER_CATEGORY && msg_type != QtMsgType::QtDebugMsg {
+            state.messages.push(message.to_string());
+            return;
+        }
+        state.previous
+    };
+    if let Some(previous) = previous {
+        previous(msg_type, context, message);
+    }
+}
+
+/// Installs `tester_message_handler` if it is not yet installed. When dropped, even if `f`
+/// panics, deletes the tester and restores the previous handler if this is the outermost guard.
+struct TesterGuard {
+    tester: *mut c_void,
+    /// Index of the first message reported while this guard exists
+    first_message: usize,
+}
+
+impl TesterGuard {
+    fn new() -> Self {
+        let mut state = tester_state();
+        if state.depth == 0 {
+            state.messages.clear();
+            state.previous = install_message_handler(Some(tester_message_handler));
+        }
+        state.depth += 1;
+        TesterGuard { tester: std::ptr::null_mut(), first_message: state.messages.len() }
+    }
+
+    fn finish(self) -> Vec<String> {
+        let first_message = self.first_message;
+        drop(self);
+        let mut state = tester_state();
+        let first_message = first_message.min(state.messages.len());
+        state.messages.split_off(first_message)
+    }
+}
+
+impl Drop for TesterGuard {
+    fn drop(&mut self) {
+        let tester = self.tester;
+        cpp!(unsafe [tester as "QObject *"] { delete tester; });
+        let mut state = tester_state();
+        state.depth -= 1;
+        if state.depth == 0 {
+            let previous = state.previous.take();
+            drop(state);
+            install_message_handler(previous);
+        }
+    }
+}
@@ -112,3 +112,34 @@ pub fn test_loading_logs(qml: &str, log: &str) -> bool {
     let logs = QML_LOGS.lock().unwrap_or_else(|e| e.into_inner());
     logs.iter().any(|x| x.contains(log))
 }
+
+/// Loads a white Rectangle filled with an item of type `T`, whose object name is "item", in a
+/// renderer of the given size. The caller must hold the lock from `lock_for_test`.
+#[cfg(qt_5_8)]
+pub fn item_renderer<T: QObject + Default + Sized>(size: QSize) -> QmlRenderer {
+    // Each type is registered in its own module, named after the type
+    let name = std::any::type_name::<T>().rsplit("::").next().unwrap();
+    let uri = std::ffi::CString::new(format!("TestItems.{}", name)).unwrap();
+    qml_register_type::<T>(&uri, 1, 0, &std::ffi::CString::new(name).unwrap());
+
+    let mut renderer = QmlRenderer::new(size);
+    let qml = format!(
+        r#"
+        import QtQuick 2.0
+        import TestItems.{0} 1.0
+        Rectangle {{
+            color: "white"
+            {0} {{ objectName: "item"; anchors.fill: parent }}
+        }}"#,
+        name
+    );
+    renderer.load_data(qml.into()).unwrap();
+    renderer
+}
+
+/// Renders an item of type `T` filling a white Rectangle of the given size
+#[cfg(qt_5_8)]
+pub fn render_item<T: QObject + Default + Sized>(size: QSize) -> QImage {
+    let _lock = lock_for_test();
+    item_renderer::<T>(size).render()
+}
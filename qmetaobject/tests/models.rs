@@ -17,6 +17,7 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 use std::cell::RefCell;
 use std::iter::FromIterator;
+use std::rc::Rc;
 
 use qmetaobject::*;
 
@@ -133,3 +134,370 @@ fn simple_model_iter() {
     let iterated_items = obj.list.borrow().iter().cloned().collect::<Vec<_>>();
     assert_eq!(original_items, iterated_items);
 }
+
+#[test]
+fn sort_filter_proxy_model() {
+    #[derive(QObject, Default)]
+    pub struct Foo {
+        base: qt_base_class!(trait QObject),
+        pub list: qt_property!(RefCell<SimpleListModel<X>>; CONST),
+        pub proxy: qt_property!(RefCell<SortFilterProxyModel<SimpleListModel<X>>>; CONST),
+        pub init: qt_method!(
+            fn init(&mut self) {
+                let proxy = unsafe { QObjectPinned::new(&self.proxy) };
+                proxy.get_or_create_cpp_object();
+                let mut proxy = proxy.borrow_mut();
+                proxy.set_source_model(unsafe { QObjectPinned::new(&self.list) });
+                proxy.set_filter(|row, source| source[row as usize].val % 2 == 0);
+                proxy.set_comparator(|left, right, source| {
+                    source[right as usize].val.cmp(&source[left as usize].val)
+                });
+            }
+        ),
+        pub push: qt_method!(
+            fn push(&mut self, val: usize) {
+                self.list.borrow_mut().push(X { val });
+            }
+        ),
+        pub remove: qt_method!(
+            fn remove(&mut self, index: usize) {
+                self.list.borrow_mut().remove(index);
+            }
+        ),
+    }
+
+    #[derive(Debug, Clone, SimpleListItem, Default)]
+    pub struct X {
+        pub val: usize,
+    }
+
+    let obj = Foo {
+        list: RefCell::new(FromIterator::from_iter(vec![
+            X { val: 10 },
+            X { val: 11 },
+            X { val: 12 },
+            X { val: 13 },
+        ])),
+        ..Default::default()
+    };
+
+    assert!(do_test(
+        obj,
+        "
+        Item {
+            Repeater {
+                id: rep
+                model: _obj.proxy
+                Text {
+                    text: val
+                }
+            }
+            function doTest() {
+                _obj.init();
+                console.log('sort_filter_proxy_model', rep.count, rep.itemAt(0).text, rep.itemAt(1).text);
+                if (rep.count !== 2 || rep.itemAt(0).text !== '12' || rep.itemAt(1).text !== '10')
+                    return false;
+                _obj.push(15);
+                _obj.push(14);
+                _obj.remove(0);
+                console.log('sort_filter_proxy_model', rep.count, rep.itemAt(0).text, rep.itemAt(1).text);
+                return rep.count === 2
+                    && rep.itemAt(0).text === '14'
+                    && rep.itemAt(1).text === '12';
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn paged_list_model() {
+    #[derive(Debug, Clone, SimpleListItem, Default)]
+    pub struct X {
+        pub val: usize,
+    }
+
+    fn fetch_all(model: Rc<RefCell<PagedListModel<X>>>, engine: Rc<QmlEngine>) {
+        let pinned = unsafe { QObjectPinned::new(&*model) };
+        if pinned.borrow().is_finished() {
+            engine.quit();
+            return;
+        }
+        if !pinned.borrow().is_loading() {
+            let m = &mut *pinned.borrow_mut() as &mut dyn QAbstractListModel;
+            assert!(m.can_fetch_more(QModelIndex::default()));
+            m.fetch_more(QModelIndex::default());
+        }
+        single_shot(std::time::Duration::from_millis(0), move || {
+            fetch_all(model.clone(), engine.clone())
+        });
+    }
+
+    let _lock = lock_for_test();
+    let engine = Rc::new(QmlEngine::new());
+    let model = Rc::new(RefCell::new(PagedListModel::new(|page| async move {
+        if page < 3 {
+            vec![X { val: page * 2 }, X { val: page * 2 + 1 }]
+        } else {
+            vec![]
+        }
+    })));
+    unsafe { QObjectPinned::new(&*model).get_or_create_cpp_object() };
+
+    fetch_all(model.clone(), engine.clone());
+    engine.exec();
+
+    let model = model.borrow();
+    assert_eq!((&*model as &dyn QAbstractListModel).row_count(), 6);
+    assert!(!(&*model as &dyn QAbstractListModel).can_fetch_more(QModelIndex::default()));
+    assert_eq!(model.iter().map(|x| x.val).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn paged_list_model_reset_while_loading() {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll, Waker};
+
+    #[derive(Debug, Clone, SimpleListItem, Default)]
+    pub struct X {
+        pub val: usize,
+    }
+
+    /// A future which is pending until `open` is called
+    #[derive(Clone, Default)]
+    struct Gate(Rc<RefCell<(bool, Option<Waker>)>>);
+    impl Gate {
+        fn open(&self) {
+            let mut state = self.0.borrow_mut();
+            state.0 = true;
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        }
+    }
+    impl Future for Gate {
+        type Output = ();
+        fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+            let mut state = self.0.borrow_mut();
+            if state.0 {
+                Poll::Ready(())
+            } else {
+                state.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    let _lock = lock_for_test();
+    let engine = Rc::new(QmlEngine::new());
+    let gate = Gate::default();
+    let stale_arrived = Rc::new(std::cell::Cell::new(false));
+    let calls = Rc::new(std::cell::Cell::new(0));
+    let model = Rc::new(RefCell::new(PagedListModel::new({
+        let (gate, stale_arrived, calls) = (gate.clone(), stale_arrived.clone(), calls.clone());
+        move |page| {
+            calls.set(calls.get() + 1);
+            let first_call = calls.get() == 1;
+            let (gate, stale_arrived) = (gate.clone(), stale_arrived.clone());
+            async move {
+                if first_call {
+                    // The stale page, which arrives after the reset
+                    gate.await;
+                    stale_arrived.set(true);
+                    vec![X { val: 100 }, X { val: 101 }]
+                } else if page < 2 {
+                    vec![X { val: page }]
+                } else {
+                    vec![]
+                }
+            }
+        }
+    })));
+    let pinned = unsafe { QObjectPinned::new(&*model) };
+    pinned.get_or_create_cpp_object();
+
+    (&mut *pinned.borrow_mut() as &mut dyn QAbstractListModel).fetch_more(QModelIndex::default());
+    assert!(pinned.borrow().is_loading());
+    pinned.borrow_mut().reset();
+    assert!(!pinned.borrow().is_loading());
+    gate.open();
+
+    fn fetch_all(
+        model: Rc<RefCell<PagedListModel<X>>>,
+        engine: Rc<QmlEngine>,
+        stale: Rc<std::cell::Cell<bool>>,
+    ) {
+        let pinned = unsafe { QObjectPinned::new(&*model) };
+        if pinned.borrow().is_finished() && stale.get() {
+            engine.quit();
+            return;
+        }
+        if !pinned.borrow().is_loading() && !pinned.borrow().is_finished() {
+            (&mut *pinned.borrow_mut() as &mut dyn QAbstractListModel)
+                .fetch_more(QModelIndex::default());
+        }
+        single_shot(std::time::Duration::from_millis(0), move || {
+            fetch_all(model.clone(), engine.clone(), stale.clone())
+        });
+    }
+    fetch_all(model.clone(), engine.clone(), stale_arrived.clone());
+    engine.exec();
+
+    let model = model.borrow();
+    assert_eq!(model.iter().map(|x| x.val).collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(calls.get(), 4);
+}
+
+#[test]
+fn table_model_header_flags_sort() {
+    #[derive(QObject, Default)]
+    pub struct Table {
+        base: qt_base_class!(trait QAbstractTableModel),
+        rows: Vec<(i32, QString)>,
+    }
+
+    impl QAbstractTableModel for Table {
+        fn row_count(&self) -> i32 {
+            self.rows.len() as i32
+        }
+        fn column_count(&self) -> i32 {
+            2
+        }
+        fn data(&self, index: QModelIndex, _role: i32) -> QVariant {
+            let row = &self.rows[index.row() as usize];
+            match index.column() {
+                0 => row.0.into(),
+                _ => row.1.clone().into(),
+            }
+        }
+        fn header_data(&self, section: i32, orientation: Orientation, role: i32) -> QVariant {
+            match (orientation, role) {
+                (Orientation::Horizontal, USER_ROLE) => {
+                    QString::from(["id", "name"][section as usize]).into()
+                }
+                _ => QVariant::default(),
+            }
+        }
+        fn flags(&self, index: QModelIndex) -> ItemFlags {
+            let flags = ItemFlags::ITEM_IS_SELECTABLE | ItemFlags::ITEM_IS_ENABLED;
+            if index.column() == 1 {
+                flags | ItemFlags::ITEM_IS_EDITABLE
+            } else {
+                flags
+            }
+        }
+        fn sort(&mut self, column: i32, order: SortOrder) {
+            (self as &mut dyn QAbstractTableModel).begin_reset_model();
+            match column {
+                0 => self.rows.sort_by_key(|r| r.0),
+                _ => self.rows.sort_by_key(|r| r.1.to_string()),
+            }
+            if order == SortOrder::DescendingOrder {
+                self.rows.reverse();
+            }
+            (self as &mut dyn QAbstractTableModel).end_reset_model();
+        }
+    }
+
+    let obj = Table {
+        rows: vec![(2, "b".into()), (3, "a".into()), (1, "c".into())],
+        ..Default::default()
+    };
+
+    assert!(do_test(
+        obj,
+        "
+        Item {
+            function doTest() {
+                if (_obj.headerData(1, Qt.Horizontal, Qt.UserRole) !== 'name')
+                    return false;
+                if (_obj.headerData(1, Qt.Vertical, Qt.UserRole) !== undefined)
+                    return false;
+                if (_obj.flags(_obj.index(0, 0)) & Qt.ItemIsEditable)
+                    return false;
+                if (!(_obj.flags(_obj.index(0, 1)) & Qt.ItemIsEditable))
+                    return false;
+                _obj.sort(0, Qt.AscendingOrder);
+                if (_obj.data(_obj.index(0, 0), Qt.DisplayRole) !== 1)
+                    return false;
+                _obj.sort(1, Qt.DescendingOrder);
+                return _obj.data(_obj.index(0, 1), Qt.DisplayRole) === 'c';
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+#[cfg(feature = "qttest")]
+#[cfg(qt_5_11)]
+fn check_simple_model() {
+    #[derive(Debug, Clone, SimpleListItem, Default)]
+    pub struct X {
+        pub val: usize,
+    }
+
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+    let model: RefCell<SimpleListModel<X>> =
+        RefCell::new(FromIterator::from_iter(vec![X { val: 1 }, X { val: 2 }]));
+    let pinned = unsafe { QObjectPinned::new(&model) };
+    qmetaobject::testing::check_model(pinned, || {
+        // The tester checks the model again on every change
+        pinned.borrow_mut().push(X { val: 3 });
+        pinned.borrow_mut().insert(0, X { val: 0 });
+        pinned.borrow_mut().remove(2);
+        pinned.borrow_mut().change_line(1, X { val: 4 });
+        pinned.borrow_mut().reset_data(vec![X { val: 5 }]);
+    });
+    assert_eq!(model.borrow().iter().map(|x| x.val).collect::<Vec<_>>(), vec![5]);
+}
+
+#[test]
+#[cfg(feature = "qttest")]
+#[cfg(qt_5_11)]
+fn check_broken_model() {
+    #[derive(QObject, Default)]
+    struct BrokenModel {
+        base: qt_base_class!(trait QAbstractListModel),
+        rows: Vec<i32>,
+    }
+    impl QAbstractListModel for BrokenModel {
+        fn row_count(&self) -> i32 {
+            self.rows.len() as i32
+        }
+        fn data(&self, index: QModelIndex, _role: i32) -> QVariant {
+            self.rows.get(index.row() as usize).map_or(QVariant::default(), |x| (*x).into())
+        }
+    }
+    impl BrokenModel {
+        /// Announces two rows, but only inserts one
+        fn broken_push(&mut self, value: i32) {
+            let len = self.rows.len() as i32;
+            (self as &mut dyn QAbstractListModel).begin_insert_rows(len, len + 1);
+            self.rows.push(value);
+            (self as &mut dyn QAbstractListModel).end_insert_rows();
+        }
+    }
+
+    extern "C" fn previous_handler(_: QtMsgType, _: &QMessageLogContext, _: &QString) {}
+
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+    let model = RefCell::new(BrokenModel { rows: vec![1, 2], ..Default::default() });
+    let pinned = unsafe { QObjectPinned::new(&model) };
+    let original = install_message_handler(Some(previous_handler));
+
+    // The model is consistent until it is modified
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        qmetaobject::testing::check_model(pinned, || pinned.borrow_mut().broken_push(3))
+    }));
+    let error = result.expect_err("check_model should have panicked");
+    let message = error.downcast_ref::<String>().unwrap();
+    assert!(message.contains("the model is inconsistent"), "{}", message);
+
+    // The message handler is restored
+    let restored = install_message_handler(original);
+    assert_eq!(restored.map(|h| h as usize), Some(previous_handler as usize));
+}
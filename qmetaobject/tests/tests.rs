@@ -934,11 +934,676 @@ fn create_component() {
 
     component.set_data(qml_text.into());
 
-    let obj = component.create();
+    let obj = component.create().unwrap();
 
     assert!(!obj.is_null());
 }
 
+#[test]
+fn create_component_error() {
+    let _lock = lock_for_test();
+    let qml_text = r"
+        import QtQuick 2.0
+
+        Item {
+            width: 10
+            NotAType {}
+        }
+    ";
+
+    let engine = QmlEngine::new();
+    let mut component = QmlComponent::new(&engine);
+    component.set_data_as(qml_text.into(), QString::from("file:///component.qml").into());
+
+    let errors = component.create().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].url, QString::from("file:///component.qml"));
+    assert_eq!(errors[0].line, 6);
+    assert!(errors[0].description.to_string().contains("NotAType"));
+    assert!(errors[0].to_string().starts_with("file:///component.qml:6:"));
+}
+
+#[test]
+fn try_load_data() {
+    let _lock = lock_for_test();
+    let mut engine = QmlEngine::new();
+
+    assert!(engine.try_load_data("import QtQuick 2.0\nItem {}".into()).is_ok());
+
+    let errors = engine
+        .try_load_data_as(
+            "import QtQuick 2.0\nItem {\n  width: 10 +\n}".into(),
+            QString::from("file:///syntax.qml").into(),
+        )
+        .unwrap_err();
+    assert!(!errors.is_empty());
+    assert_eq!(errors[0].url, QString::from("file:///syntax.qml"));
+    assert!(errors[0].line >= 3);
+
+    let errors = engine.try_load_data("import NotAModule 1.0\nItem {}".into()).unwrap_err();
+    assert!(errors.iter().any(|e| e.description.to_string().contains("NotAModule")));
+}
+
+#[test]
+fn create_with_initial_properties() {
+    let _lock = lock_for_test();
+    let engine = Rc::new(QmlEngine::new());
+    let mut component = QmlComponent::new(&engine);
+    component.set_data(
+        r#"
+        import QtQuick 2.0
+        Item {
+            property int value: 1
+            property int valueOnCompleted: 0
+            property string label
+            Component.onCompleted: valueOnCompleted = value
+        }"#
+        .into(),
+    );
+
+    let mut properties = QVariantMap::default();
+    properties.insert("value".into(), 42.into());
+    properties.insert("label".into(), QString::from("hello").into());
+    let obj = component.create_with_initial_properties(&properties).unwrap();
+    assert_eq!(obj.property("value".into()).to_int(), 42);
+    assert_eq!(obj.property("valueOnCompleted".into()).to_int(), 42);
+    assert_eq!(obj.property("label".into()).to_qstring(), QString::from("hello"));
+
+    let mut properties = QVariantMap::default();
+    properties.insert("notAProperty".into(), 42.into());
+    assert!(!component.create_with_initial_properties(&properties).unwrap_err().is_empty());
+
+    let parent = obj.clone();
+    let child = component.create_with_initial_properties(&QVariantMap::default()).unwrap();
+    child.set_parent(&parent);
+    assert_eq!(child.parent().as_cpp_ptr(), obj.as_cpp_ptr());
+    obj.delete_later();
+    let engine2 = engine.clone();
+    single_shot(std::time::Duration::from_millis(0), move || engine2.quit());
+    engine.exec();
+    assert!(obj.is_null());
+    assert!(child.is_null());
+}
+
+#[test]
+fn incubate_component() {
+    if_rust_version!(>= 1.39 {
+        let _lock = lock_for_test();
+        let engine = Rc::new(QmlEngine::new());
+        let mut component = QmlComponent::new(&engine);
+        component.set_data("import QtQuick 2.0; Item { property int value: 42 }".into());
+
+        let statuses = Rc::new(RefCell::new(Vec::new()));
+        let mut incubator = component.incubate(IncubationMode::Asynchronous);
+        let statuses2 = statuses.clone();
+        incubator.on_status_changed(move |status| statuses2.borrow_mut().push(status));
+        assert_eq!(incubator.status(), IncubatorStatus::Loading);
+
+        let result = Rc::new(RefCell::new(None));
+        let result2 = result.clone();
+        let engine2 = engine.clone();
+        future::execute_async(async move {
+            let obj = incubator.await.unwrap();
+            *result2.borrow_mut() = Some(obj.property("value".into()).to_int());
+            obj.delete_later();
+            engine2.quit();
+        });
+        engine.exec();
+        assert_eq!(*result.borrow(), Some(42));
+        assert_eq!(statuses.borrow().last(), Some(&IncubatorStatus::Ready));
+    });
+}
+
+#[test]
+fn incubate_force_completion_and_errors() {
+    let _lock = lock_for_test();
+    let engine = QmlEngine::new();
+    let mut component = QmlComponent::new(&engine);
+    component.set_data("import QtQuick 2.0; Item { property int value: 42 }".into());
+    let mut incubator = component.incubate(IncubationMode::Asynchronous);
+    incubator.force_completion();
+    assert_eq!(incubator.status(), IncubatorStatus::Ready);
+    let obj = incubator.object().unwrap();
+    assert_eq!(obj.property("value".into()).to_int(), 42);
+    obj.delete_later();
+
+    component.set_data("import QtQuick 2.0; Item { property int value: }".into());
+    let incubator = component.incubate(IncubationMode::Asynchronous);
+    assert_eq!(incubator.status(), IncubatorStatus::Null);
+    assert!(incubator.object().is_none());
+    assert!(!incubator.errors().is_empty());
+}
+
+#[test]
+fn qjsvalue_objects_and_calls() {
+    #[derive(QObject, Default)]
+    struct JsCallbacks {
+        base: qt_base_class!(trait QObject),
+        check: qt_method!(
+            fn check(&self, f: QJSValue, o: QJSValue) -> bool {
+                assert!(f.is_callable());
+                assert_eq!(f.call(&[QJSValue::from(2), QJSValue::from(3)]).to_number(), 5.);
+                assert!(o.is_object());
+                assert!(!o.is_array());
+                assert_eq!(o.property("x".into()).to_number(), 1.);
+                assert!(o.property("nothing".into()).is_undefined());
+                assert!(o.property("nil".into()).is_null());
+
+                let arr = o.property("arr".into());
+                assert!(arr.is_array());
+                assert_eq!(arr.property_at(1).to_string(), QString::from("b"));
+
+                let names: Vec<String> = o.properties().map(|(name, _)| name.to_string()).collect();
+                assert_eq!(names, ["x", "nil", "arr", "addX", "Point", "thrower"]);
+
+                let add_x = o.property("addX".into());
+                assert_eq!(add_x.call_with_instance(&o, &[QJSValue::from(4)]).to_number(), 5.);
+                let point = o.property("Point".into()).call_as_constructor(&[QJSValue::from(7)]);
+                assert_eq!(point.property("v".into()).to_number(), 7.);
+                assert!(o.property("thrower".into()).call(&[]).is_error());
+
+                let mut o = o;
+                o.set_property("y".into(), QJSValue::from(QString::from("set from rust")));
+                o.set_property("x".into(), QJSValue::from(10));
+                true
+            }
+        ),
+    }
+
+    let obj = JsCallbacks::default();
+    assert!(do_test(
+        obj,
+        r"
+        Item {
+            function doTest() {
+                var o = {
+                    x: 1,
+                    nil: null,
+                    arr: ['a', 'b'],
+                    addX: function(v) { return this.x + v; },
+                    Point: function(v) { this.v = v; },
+                    thrower: function() { throw new Error('error'); }
+                };
+                return _obj.check(function(a, b) { return a + b; }, o)
+                    && o.x === 10 && o.y === 'set from rust';
+            }
+        }"
+    ));
+}
+
+#[test]
+fn qjsvalue_from_engine() {
+    let _lock = lock_for_test();
+    let engine = QmlEngine::new();
+    let mut obj = engine.new_object();
+    assert!(obj.is_object());
+    obj.set_property("name".into(), QString::from("Konqui").into());
+    assert!(obj.has_property("name".into()));
+    assert_eq!(obj.property("name".into()).to_string(), QString::from("Konqui"));
+    assert!(obj.delete_property("name".into()));
+    assert!(!obj.has_property("name".into()));
+
+    let mut arr = engine.new_array(2);
+    assert!(arr.is_array());
+    arr.set_property_at(0, 10.into());
+    arr.set_property_at(1, 20.into());
+    assert_eq!(arr.property("length".into()).to_number(), 2.);
+    assert_eq!(arr.property_at(1).to_number(), 20.);
+
+    let list: QVariantList = vec![1u32, 2u32, 3u32].iter().collect();
+    let value = engine.to_js_value(list.into());
+    assert!(value.is_array());
+    assert_eq!(value.property_at(2).to_number(), 3.);
+
+    let math = engine.global_object().property("Math".into());
+    let max = math.property("max".into());
+    assert_eq!(max.call_with_instance(&math, &[1.into(), 5.into(), 3.into()]).to_number(), 5.);
+}
+
+#[test]
+fn js_engine_evaluate() {
+    let _lock = lock_for_test();
+    let mut engine = JsEngine::new();
+    let result = engine.evaluate("1 + 2".into(), "script.js".into(), 1).unwrap();
+    assert_eq!(result.to_number(), 3.);
+
+    let mut global = engine.global_object();
+    global.set_property("factor".into(), 10.into());
+    let rule = engine
+        .evaluate("(function(v) { return v * factor; })".into(), "rule.js".into(), 1)
+        .unwrap();
+    assert!(rule.is_callable());
+    assert_eq!(rule.call(&[QJSValue::from(4)]).to_number(), 40.);
+
+    let err = engine.evaluate("\n\nnotDefined()".into(), "error.js".into(), 1).unwrap_err();
+    assert_eq!(err.name, QString::from("ReferenceError"));
+    assert_eq!(err.line, 3);
+    assert!(err.to_string().starts_with("error.js:3: ReferenceError: "), "{}", err);
+
+    let err = engine.evaluate("throw 'oops'".into(), "throw.js".into(), 1).unwrap_err();
+    assert_eq!(err.message, QString::from("oops"));
+
+    assert!(engine.evaluate("function (".into(), "syntax.js".into(), 1).is_err());
+}
+
+#[test]
+fn js_engine_objects_and_extensions() {
+    let _lock = lock_for_test();
+    let mut engine = JsEngine::new();
+    let mut obj = engine.new_object();
+    obj.set_property("list".into(), engine.new_array(3));
+    engine.global_object().set_property("obj".into(), obj);
+    let len = engine.evaluate("obj.list.length".into(), QString::default(), 1).unwrap();
+    assert_eq!(len.to_number(), 3.);
+
+    #[derive(QObject, Default)]
+    struct Rules {
+        base: qt_base_class!(trait QObject),
+        threshold: qt_property!(u32),
+    }
+    let rules = engine.new_qobject(Rules { threshold: 12, ..Default::default() });
+    engine.global_object().set_property("rules".into(), rules);
+    let v = engine.evaluate("rules.threshold + 1".into(), QString::default(), 1).unwrap();
+    assert_eq!(v.to_number(), 13.);
+
+    assert!(engine.evaluate("console.log('hello')".into(), QString::default(), 1).is_err());
+    engine.install_extensions(JsExtensions::CONSOLE | JsExtensions::GARBAGE_COLLECTION, None);
+    engine.evaluate("console.log('hello'); gc()".into(), QString::default(), 1).unwrap();
+    engine.collect_garbage();
+}
+
+fn colored_image(name: &QString, size: QSize) -> QImage {
+    let mut image = QImage::new(size, ImageFormat::ARGB32);
+    image.fill(QColor::from_name(&name.to_string()));
+    image
+}
+
+#[test]
+fn image_provider() {
+    struct ColorProvider;
+    impl QmlImageProvider for ColorProvider {
+        fn request_image(&self, id: QString, requested_size: QSize) -> (QImage, QSize) {
+            let size = if requested_size.width > 0 {
+                requested_size
+            } else {
+                QSize { width: 10, height: 20 }
+            };
+            (colored_image(&id, size), QSize { width: 10, height: 20 })
+        }
+    }
+
+    let _lock = lock_for_test();
+    let mut engine = QmlEngine::new();
+    engine.add_image_provider("colors".into(), ColorProvider);
+    engine.load_data(
+        r#"
+        import QtQuick 2.0
+        Item {
+            property alias status: img.status
+            property alias implicitImageWidth: img.implicitWidth
+            property alias sizedImageWidth: sized.implicitWidth
+            Image { id: img; source: "image://colors/red" }
+            Image { id: sized; source: "image://colors/blue"; sourceSize.width: 5; sourceSize.height: 5 }
+        }"#
+        .into(),
+    );
+    let root = engine.root_objects().pop().unwrap();
+    assert_eq!(root.property("status".into()).to_int(), 1); // Image.Ready
+    assert_eq!(root.property("implicitImageWidth".into()).to_int(), 10);
+    assert_eq!(root.property("sizedImageWidth".into()).to_int(), 5);
+}
+
+#[test]
+fn async_image_provider() {
+    struct ThreadedProvider {
+        requests: Rc<RefCell<Vec<String>>>,
+    }
+    impl QmlAsyncImageProvider for ThreadedProvider {
+        fn request_image_response(&self, id: QString, _size: QSize, response: QmlImageResponse) {
+            self.requests.borrow_mut().push(id.to_string());
+            std::thread::spawn(move || {
+                if id == QString::from("error") {
+                    response.fail("no such image".into());
+                } else {
+                    response.finish(colored_image(&id, QSize { width: 7, height: 3 }));
+                }
+            });
+        }
+    }
+
+    let _lock = lock_for_test();
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let mut engine = QmlEngine::new();
+    engine.add_async_image_provider(
+        "threaded".into(),
+        ThreadedProvider { requests: requests.clone() },
+    );
+    engine.load_data(
+        r#"
+        import QtQuick 2.0
+        Item {
+            property int readyCount: 0
+            property int errorCount: 0
+            property int loadedWidth: ok.implicitWidth
+            function check(img) {
+                if (img.status == Image.Ready) readyCount++;
+                if (img.status == Image.Error) errorCount++;
+                if (readyCount + errorCount == 2) done();
+            }
+            signal done
+            Image { id: ok; source: "image://threaded/green"; onStatusChanged: check(ok) }
+            Image { id: ko; source: "image://threaded/error"; onStatusChanged: check(ko) }
+        }"#
+        .into(),
+    );
+    let root = engine.root_objects().pop().unwrap();
+    let engine = Rc::new(engine);
+    let engine2 = engine.clone();
+    root.connect_signal("done".into(), move |_| engine2.quit()).unwrap();
+    let engine3 = engine.clone();
+    single_shot(std::time::Duration::from_secs(10), move || engine3.quit());
+    engine.exec();
+    assert_eq!(root.property("readyCount".into()).to_int(), 1);
+    assert_eq!(root.property("errorCount".into()).to_int(), 1);
+    assert_eq!(root.property("loadedWidth".into()).to_int(), 7);
+    requests.borrow_mut().sort();
+    assert_eq!(*requests.borrow(), ["error", "green"]);
+}
+
+#[test]
+fn url_interceptor_and_network_factory() {
+    use std::sync::{Arc, Mutex};
+
+    struct LocalServer {
+        served: Arc<Mutex<Vec<String>>>,
+    }
+    impl QmlNetworkAccessManagerFactory for LocalServer {
+        fn prepare_request(&self, request: &mut QNetworkRequest) {
+            request.set_raw_header("Authorization".into(), "Bearer secret".into());
+        }
+        fn serve_request(
+            &self,
+            request: &QNetworkRequest,
+        ) -> Option<Result<NetworkReplyData, QString>> {
+            let url = request.url();
+            if url.scheme() != QString::from("rust") {
+                return None;
+            }
+            let auth = request.raw_header("Authorization".into());
+            self.served.lock().unwrap().push(format!("{} {}", url.path(), auth));
+            if url.path() == QString::from("/Answer.qml") {
+                Some(Ok(NetworkReplyData {
+                    content: "import QtQuick 2.0; Item { property int answer: 42 }".into(),
+                    content_type: "text/plain".into(),
+                }))
+            } else {
+                Some(Err("not found".into()))
+            }
+        }
+    }
+
+    let _lock = lock_for_test();
+    let served = Arc::new(Mutex::new(Vec::new()));
+    let intercepted = Arc::new(Mutex::new(Vec::new()));
+    let mut engine = QmlEngine::new();
+    engine.set_network_access_manager_factory(LocalServer { served: served.clone() });
+    let intercepted2 = intercepted.clone();
+    engine.add_url_interceptor(move |url: QUrl, data_type: UrlDataType| {
+        if url.scheme() != QString::from("app") {
+            return url;
+        }
+        intercepted2.lock().unwrap().push((url.path().to_string(), data_type));
+        QUrl::from(QString::from(format!("rust://local{}", url.path())))
+    });
+    engine.load_data(
+        r#"
+        import QtQuick 2.0
+        Item {
+            signal done
+            property int finished: 0
+            property alias answer: ok.answer
+            property alias missingStatus: missing.status
+            function check() { if (++finished == 2) done(); }
+            Loader {
+                id: ok
+                property int answer: item ? item.answer : 0
+                source: "app:///Answer.qml"
+                onStatusChanged: if (status == Loader.Ready || status == Loader.Error) check()
+            }
+            Loader {
+                id: missing
+                source: "app:///Missing.qml"
+                onStatusChanged: if (status == Loader.Ready || status == Loader.Error) check()
+            }
+        }"#
+        .into(),
+    );
+    let root = engine.root_objects().pop().unwrap();
+    let engine = Rc::new(engine);
+    let engine2 = engine.clone();
+    root.connect_signal("done".into(), move |_| engine2.quit()).unwrap();
+    let engine3 = engine.clone();
+    single_shot(std::time::Duration::from_secs(10), move || engine3.quit());
+    engine.exec();
+
+    assert_eq!(root.property("answer".into()).to_int(), 42);
+    assert_eq!(root.property("missingStatus".into()).to_int(), 3); // Loader.Error
+    let mut served = served.lock().unwrap().clone();
+    served.sort();
+    assert_eq!(served, ["/Answer.qml Bearer secret", "/Missing.qml Bearer secret"]);
+    // Loader resolves its source as a URL string before loading it as a QML file
+    assert!(intercepted.lock().unwrap().iter().any(|(path, _)| path == "/Answer.qml"));
+}
+
+#[test]
+#[cfg(qt_5_8)]
+fn qml_renderer() {
+    let _lock = lock_for_test();
+    let mut renderer = QmlRenderer::new(QSize { width: 40, height: 30 });
+    renderer
+        .load_data(
+            r#"
+            import QtQuick 2.0
+            Rectangle {
+                color: "white"
+                property alias boxX: box.x
+                Rectangle {
+                    id: box
+                    width: 10; height: 10
+                    color: "red"
+                    NumberAnimation on x { from: 0; to: 30; duration: 1000 }
+                }
+            }"#
+            .into(),
+        )
+        .unwrap();
+
+    let first = renderer.render();
+    assert_eq!(first.size(), QSize { width: 40, height: 30 });
+    assert_eq!(first.get_pixel_color(5, 5), QColor::from_name("red"));
+    assert_eq!(first.get_pixel_color(35, 25), QColor::from_name("white"));
+
+    renderer.advance(std::time::Duration::from_millis(500));
+    let root = renderer.root_object().unwrap();
+    let x = root.property("boxX".into()).to_qbytearray().to_string().parse::<f64>().unwrap();
+    assert!((x - 15.).abs() < 1., "x = {}", x);
+    let second = renderer.render();
+    assert_eq!(second.get_pixel_color(5, 5), QColor::from_name("white"));
+    assert_eq!(second.get_pixel_color(20, 5), QColor::from_name("red"));
+
+    let exact = ImageTolerance::default();
+    assert!(compare_images(&second, &second, exact).is_ok());
+    match compare_images(&first, &second, exact) {
+        Err(ImageMismatch::PixelMismatch { differing_pixels, max_channel_difference }) => {
+            assert!(differing_pixels > 0);
+            assert_eq!(max_channel_difference, 255);
+        }
+        r => panic!("unexpected result {:?}", r),
+    }
+    let lenient = ImageTolerance { max_channel_difference: 0, max_differing_pixels: 40 * 30 };
+    assert!(compare_images(&first, &second, lenient).is_ok());
+
+    let dir = tempfile::tempdir().unwrap();
+    let reference = dir.path().join("scene.png");
+    assert!(matches!(
+        compare_with_reference(&second, &reference, exact),
+        Err(ImageMismatch::MissingReference(_))
+    ));
+    std::fs::rename(dir.path().join("scene.actual.png"), &reference).unwrap();
+    compare_with_reference(&second, &reference, exact).unwrap();
+    assert!(compare_with_reference(&first, &reference, exact).is_err());
+
+    assert!(renderer.load_data("import QtQuick 2.0; QtObject {}".into()).is_err());
+}
+
+#[test]
+#[cfg(feature = "hot_reload")]
+fn hot_reload() {
+    #[derive(QObject, Default)]
+    struct Backend {
+        base: qt_base_class!(trait QObject),
+        value: qt_property!(u32),
+    }
+
+    let _lock = lock_for_test();
+    let dir = tempfile::tempdir().unwrap();
+    let main_qml = dir.path().join("main.qml");
+    std::fs::write(&main_qml, "import QtQuick 2.0\nItem { property int version: 1 }").unwrap();
+
+    let backend = RefCell::new(Backend { value: 42, ..Default::default() });
+    let mut engine = QmlEngine::new();
+    engine.set_object_property("backend".into(), unsafe { QObjectPinned::new(&backend) });
+    engine.load_file(main_qml.to_str().unwrap().into());
+    assert_eq!(engine.root_objects().len(), 1);
+    engine.enable_hot_reload(dir.path().to_str().unwrap().into());
+
+    let engine = Rc::new(engine);
+    let results = Rc::new(RefCell::new(Vec::new()));
+    let results2 = results.clone();
+    // Weak, since the engine owns the callback
+    let engine2 = Rc::downgrade(&engine);
+    let main_qml2 = main_qml.clone();
+    engine.on_hot_reload(move |result| {
+        let engine2 = engine2.upgrade().unwrap();
+        let roots = engine2.root_objects();
+        let version = roots.last().map(|r| r.property("version".into()).to_int());
+        let backend = roots.last().map(|r| r.property("backendValue".into()).to_int());
+        results2.borrow_mut().push((result.is_ok(), roots.len(), version, backend));
+        if result.is_ok() {
+            let broken = "import QtQuick 2.0\nItem { property int version: }";
+            std::fs::write(&main_qml2, broken).unwrap();
+        } else {
+            engine2.quit();
+        }
+    });
+
+    single_shot(std::time::Duration::from_millis(100), move || {
+        let qml = "import QtQuick 2.0\n\
+                   Item { property int version: 2; property int backendValue: backend.value }";
+        std::fs::write(&main_qml, qml).unwrap();
+    });
+    let engine3 = engine.clone();
+    single_shot(std::time::Duration::from_secs(10), move || engine3.quit());
+    engine.exec();
+
+    assert_eq!(*results.borrow(), [(true, 1, Some(2), Some(42)), (false, 0, None, None)]);
+}
+
+#[test]
+fn component_in_child_contexts() {
+    let _lock = lock_for_test();
+    let engine = QmlEngine::new();
+    let mut root = engine.root_context();
+    root.set_context_property("prefix".into(), QString::from("item ").into());
+
+    let mut component = QmlComponent::new(&engine);
+    component.set_data_as(
+        "import QtQuick 2.0\nText { text: prefix + value }".into(),
+        QString::from("file:///Item.qml").into(),
+    );
+
+    let mut contexts = Vec::new();
+    let mut objects = Vec::new();
+    for i in 0..2 {
+        let mut context = QmlContext::new_child(&root);
+        context.set_context_property("value".into(), QVariant::from(i));
+        assert_eq!(context.context_property("value".into()).to_int(), i as u32);
+        assert_eq!(context.context_property("prefix".into()).to_qstring(), QString::from("item "));
+        let obj = component.create_in(&context).unwrap();
+        objects.push(unsafe { QObjectRef::from_cpp_ptr(obj) });
+        contexts.push(context);
+    }
+    assert_eq!(objects[0].property("text".into()).to_qstring(), QString::from("item 0"));
+    assert_eq!(objects[1].property("text".into()).to_qstring(), QString::from("item 1"));
+
+    contexts[1].set_context_property("value".into(), QVariant::from(5));
+    assert_eq!(objects[1].property("text".into()).to_qstring(), QString::from("item 5"));
+    assert_eq!(objects[0].property("text".into()).to_qstring(), QString::from("item 0"));
+
+    assert!(contexts[0].is_valid());
+    root.set_base_url(QString::from("file:///base/").into());
+    let child = QmlContext::new_child(&root);
+    assert_eq!(child.context_property("prefix".into()).to_qstring(), QString::from("item "));
+    assert_eq!(QString::from(root.base_url()), QString::from("file:///base/"));
+}
+
+#[test]
+fn root_objects_and_find_child() {
+    let _lock = lock_for_test();
+    let mut engine = QmlEngine::new();
+    engine.load_data(
+        r#"
+        import QtQuick 2.0
+        Item {
+            objectName: "root"
+            signal activated(int value, string name)
+            Rectangle { objectName: "rect"; width: 10 }
+            Item { Text { objectName: "text"; text: "hello" } }
+            Text { objectName: "text"; text: "world" }
+            function activate() { activated(42, "foo"); }
+        }"#
+        .into(),
+    );
+
+    let roots = engine.root_objects();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].object_name(), QString::from("root"));
+    assert_eq!(engine.find_child("root".into()).unwrap().as_cpp_ptr(), roots[0].as_cpp_ptr());
+    assert!(engine.find_child("nothing".into()).is_none());
+
+    let texts = engine.find_children("text".into());
+    assert_eq!(texts.len(), 2);
+    assert_eq!(texts[0].property("text".into()).to_qstring(), QString::from("hello"));
+
+    let rect = roots[0].find_child("rect".into()).unwrap();
+    assert_eq!(rect.property("width".into()).to_int(), 10);
+    let width_changes = Rc::new(RefCell::new(Vec::new()));
+    let width_changes2 = width_changes.clone();
+    let rect2 = rect.clone();
+    let mut width_connection = rect
+        .connect_signal("widthChanged".into(), move |_| {
+            width_changes2.borrow_mut().push(rect2.property("width".into()).to_int());
+        })
+        .unwrap();
+    assert!(rect.set_property("width".into(), 20.into()));
+    assert_eq!(*width_changes.borrow(), vec![20]);
+    width_connection.disconnect();
+    assert_eq!(Rc::strong_count(&width_changes), 1);
+    assert!(rect.set_property("width".into(), 30.into()));
+    assert_eq!(*width_changes.borrow(), vec![20]);
+
+    let activated = Rc::new(RefCell::new(None));
+    let activated2 = activated.clone();
+    roots[0]
+        .connect_signal("activated".into(), move |args| {
+            *activated2.borrow_mut() = Some((args[0].to_int(), args[1].to_qstring()));
+        })
+        .unwrap();
+    assert!(roots[0].connect_signal("notASignal".into(), |_| {}).is_none());
+    engine.invoke_method_noreturn("activate".into(), &[]);
+    assert_eq!(*activated.borrow(), Some((42, QString::from("foo"))));
+}
+
 #[test]
 fn component_status_changed() {
     if_rust_version!(>= 1.39 {
@@ -1070,3 +1735,553 @@ fn test_setting_context_object() {
     let res: Option<i32> = QMetaType::from_qvariant(res);
     assert_eq!(res, Some(12));
 }
+
+#[derive(Default, QObject)]
+struct ManagedItem {
+    base: qt_base_class!(trait QQuickItem),
+    changes: Vec<String>,
+    check: qt_method!(
+        fn check(&self) -> bool {
+            let item = self as &dyn QQuickItem;
+            let children = item.child_items();
+            let names: Vec<String> =
+                children.iter().map(|c| c.object_name().to_string()).collect();
+            if names != ["first", "second"]
+                || item.parent_item().object_name() != QString::from("root")
+            {
+                return false;
+            }
+            let other = item.parent_item().find_child("other".into()).unwrap();
+            let origin = QPointF { x: 0., y: 0. };
+            if item.map_to_scene(origin) != QPointF { x: 10., y: 20. }
+                || item.map_from_scene(QPointF { x: 15., y: 25. }) != QPointF { x: 5., y: 5. }
+                || item.map_to_item(&other, origin) != QPointF { x: -40., y: -30. }
+                || item.map_from_item(&children[0], QPointF { x: 1., y: 1. })
+                    != QPointF { x: 6., y: 6. }
+            {
+                return false;
+            }
+
+            let flags = QQuickItemFlags::ITEM_HAS_CONTENTS | QQuickItemFlags::ITEM_ACCEPTS_DROPS;
+            item.set_flag(flags, true);
+            item.set_flag(QQuickItemFlags::ITEM_ACCEPTS_DROPS, false);
+            let buttons = MouseButtons::LEFT_BUTTON | MouseButtons::RIGHT_BUTTON;
+            item.set_accepted_mouse_buttons(buttons);
+            item.set_accept_hover_events(true);
+            item.set_cursor(CursorShape::PointingHandCursor);
+            item.set_implicit_size(30., 40.);
+            item.set_focus(true);
+            item.flags() == QQuickItemFlags::ITEM_HAS_CONTENTS
+                && item.accepted_mouse_buttons() == buttons
+                && item.has_focus()
+        }
+    ),
+    reparent: qt_method!(
+        fn reparent(&self) {
+            let item = self as &dyn QQuickItem;
+            let other = item.parent_item().find_child("other".into()).unwrap();
+            item.set_parent_item(&other);
+        }
+    ),
+    changes_string: qt_method!(
+        fn changes_string(&self) -> QString {
+            self.changes.join(",").into()
+        }
+    ),
+}
+
+impl QQuickItem for ManagedItem {
+    fn item_change(&mut self, change: ItemChange) {
+        self.changes.push(match change {
+            ItemChange::ChildAdded(_) => "ChildAdded".into(),
+            ItemChange::VisibleHasChanged(visible) => format!("Visible:{}", visible),
+            ItemChange::ParentHasChanged(parent) => format!("Parent:{}", parent.object_name()),
+            _ => return,
+        });
+    }
+}
+
+#[test]
+fn quick_item_accessors() {
+    qml_register_type::<ManagedItem>(
+        CStr::from_bytes_with_nul(b"TestManagedItem\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"ManagedItem\0").unwrap(),
+    );
+
+    let obj = MyObject::default(); // not used but needed for do_test
+    assert!(do_test(
+        obj,
+        r#"
+        import TestManagedItem 1.0
+
+        Item {
+            objectName: "root"
+            ManagedItem {
+                id: managed
+                x: 10; y: 20
+                Item { objectName: "first"; x: 5; y: 5 }
+                Item { objectName: "second" }
+            }
+            Item { id: other; objectName: "other"; x: 50; y: 50 }
+            function doTest() {
+                if (!managed.check())
+                    return false;
+                managed.visible = false;
+                managed.reparent();
+                var changes = managed.changes_string();
+                return managed.implicitWidth === 30 && managed.implicitHeight === 40
+                    && managed.parent === other
+                    && changes.split("ChildAdded").length === 3
+                    && changes.endsWith("Visible:false,Parent:other");
+            }
+        }
+        "#
+    ));
+}
+
+#[test]
+fn item_change_from_component_complete() {
+    #[derive(Default, QObject)]
+    struct ReentrantItem {
+        base: qt_base_class!(trait QQuickItem),
+        parents: Vec<String>,
+        parents_string: qt_method!(
+            fn parents_string(&self) -> QString {
+                self.parents.join(",").into()
+            }
+        ),
+    }
+
+    impl QQuickItem for ReentrantItem {
+        fn component_complete(&mut self) {
+            let item = self as &dyn QQuickItem;
+            let other = item.parent_item().find_child("other".into()).unwrap();
+            // The item is borrowed, so the notifications come later
+            item.set_parent_item(&other);
+            item.set_focus(true);
+            assert!(!self.parents.contains(&"other".to_owned()));
+        }
+
+        fn item_change(&mut self, change: ItemChange) {
+            if let ItemChange::ParentHasChanged(parent) = change {
+                self.parents.push(parent.object_name().to_string());
+            }
+        }
+    }
+
+    qml_register_type::<ReentrantItem>(
+        CStr::from_bytes_with_nul(b"TestReentrantItem\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"ReentrantItem\0").unwrap(),
+    );
+
+    let _lock = lock_for_test();
+    let engine = Rc::new(RefCell::new(QmlEngine::new()));
+    engine.borrow_mut().load_data(
+        r#"
+        import QtQuick 2.0
+        import TestReentrantItem 1.0
+        Item {
+            objectName: "root"
+            Item { objectName: "other" }
+            ReentrantItem { id: reentrant }
+            function parents() {
+                return reentrant.focus && reentrant.parent.objectName === "other"
+                    ? reentrant.parents_string() : "";
+            }
+        }"#
+        .into(),
+    );
+    let engine2 = engine.clone();
+    single_shot(std::time::Duration::from_millis(0), move || engine2.borrow().quit());
+    engine.borrow().exec();
+    let parents = engine.borrow_mut().invoke_method("parents".into(), &[]).to_qstring();
+    assert!(parents.to_string().ends_with("other"), "{}", parents);
+}
+
+#[test]
+#[cfg(qt_5_8)]
+fn focus_events() {
+    #[derive(Default, QObject)]
+    struct FocusItem {
+        base: qt_base_class!(trait QQuickItem),
+        focused: qt_property!(bool; WRITE set_focused),
+        log: qt_property!(QString; READ log_string),
+        events: Vec<String>,
+    }
+
+    impl FocusItem {
+        // The focus events are sent while the item is borrowed by the setter
+        fn set_focused(&mut self, focused: bool) {
+            let item = self as &dyn QQuickItem;
+            if focused {
+                item.force_active_focus();
+            } else {
+                item.set_focus(false);
+            }
+        }
+
+        fn log_string(&self) -> QString {
+            self.events.join(",").into()
+        }
+    }
+
+    impl QQuickItem for FocusItem {
+        fn focus_in_event(&mut self, event: QFocusEvent) {
+            self.events.push(format!("focus_in:{:?}", event.reason()));
+        }
+        fn focus_out_event(&mut self, event: QFocusEvent) {
+            self.events.push(format!("focus_out:{:?}", event.reason()));
+        }
+    }
+
+    let _lock = lock_for_test();
+    let mut renderer = item_renderer::<FocusItem>(QSize { width: 50, height: 50 });
+    // Activates the window
+    renderer.render();
+
+    let item = renderer.root_object().unwrap().find_child("item".into()).unwrap();
+    let log = || item.property("log".into()).to_qstring().to_string();
+
+    assert!(item.set_property("focused".into(), true.into()));
+    assert!(item.property("activeFocus".into()).to_bool());
+    assert_eq!(log(), "");
+    renderer.render();
+    assert_eq!(log(), "focus_in:OtherFocusReason");
+
+    assert!(item.set_property("focused".into(), false.into()));
+    assert!(!item.property("activeFocus".into()).to_bool());
+    renderer.render();
+    assert_eq!(log(), "focus_in:OtherFocusReason,focus_out:OtherFocusReason");
+}
+
+#[test]
+fn geometry_node() {
+    use qmetaobject::scenegraph::*;
+    assert_eq!(std::mem::size_of::<Point2D>(), 8);
+    assert_eq!(std::mem::size_of::<ColoredPoint2D>(), 12);
+    assert_eq!(std::mem::size_of::<TexturedPoint2D>(), 16);
+    assert_eq!(
+        ColoredPoint2D::new(1., 2., QColor::from_rgba(255, 0, 51, 102)),
+        ColoredPoint2D { x: 1., y: 2., r: 102, g: 0, b: 20, a: 102 }
+    );
+
+    let mut node: SGNode<GeometryNode<ColoredPoint2D>> =
+        unsafe { SGNode::from_raw(std::ptr::null_mut()) };
+    node.create(DrawingMode::Triangles);
+    let raw = node.raw;
+    node.create(DrawingMode::Triangles);
+    assert_eq!(node.raw, raw);
+    let red = QColor::from_name("red");
+    let vertices = [
+        ColoredPoint2D::new(0., 0., red),
+        ColoredPoint2D::new(10., 0., red),
+        ColoredPoint2D::new(10., 10., red),
+        ColoredPoint2D::new(0., 10., red),
+    ];
+    node.set_indexed_vertices(&vertices, &[0, 1, 2, 0, 2, 3]);
+    node.set_vertices(&[]);
+    node.set_drawing_mode(DrawingMode::LineStrip);
+    node.set_line_width(2.);
+    node.set_color(red);
+
+    let mut flat: SGNode<GeometryNode<Point2D>> = unsafe { SGNode::from_raw(std::ptr::null_mut()) };
+    flat.create(DrawingMode::Lines);
+    flat.set_color(red);
+    flat.set_vertices(&[Point2D { x: 0., y: 0. }, Point2D { x: 5., y: 5. }]);
+}
+
+#[test]
+#[cfg(qt_5_8)]
+fn scene_graph_nodes() {
+    use qmetaobject::scenegraph::*;
+
+    #[derive(Default, QObject)]
+    struct SceneNodesItem {
+        base: qt_base_class!(trait QQuickItem),
+    }
+
+    impl QQuickItem for SceneNodesItem {
+        fn component_complete(&mut self) {
+            (self as &dyn QQuickItem).set_flag(QQuickItemFlags::ITEM_HAS_CONTENTS, true);
+        }
+
+        fn update_paint_node(&mut self, mut node: SGNode<ContainerNode>) -> SGNode<ContainerNode> {
+            let item = self as &dyn QQuickItem;
+            let rect = item.bounding_rect();
+            let image = colored_image(&"red".into(), QSize { width: 4, height: 3 });
+            // The left half of the item is drawn in red, with an opacity of 0.5
+            node.update_static(|mut clip: SGNode<ClipNode>| -> SGNode<ClipNode> {
+                clip.set_clip_rect(QRectF { width: rect.width / 2., ..rect });
+                clip.update_sub_node(|mut sub| {
+                    sub.update_static(|mut opacity: SGNode<OpacityNode>| -> SGNode<OpacityNode> {
+                        opacity.set_opacity(0.5);
+                        opacity.update_sub_node(|mut sub| {
+                            sub.update_static(|mut n: SGNode<ImageNode>| -> SGNode<ImageNode> {
+                                n.create(item);
+                                n.set_image(item, &image);
+                                n.set_filtering(TextureFiltering::Nearest);
+                                n.set_rect(rect);
+                                n
+                            });
+                            sub
+                        });
+                        opacity
+                    });
+                    sub
+                });
+                clip
+            });
+            node
+        }
+    }
+
+    let image = render_item::<SceneNodesItem>(QSize { width: 40, height: 30 });
+    let (r, g, b, _) = image.get_pixel_color(5, 5).get_rgba();
+    assert_eq!(r, 255);
+    assert!((126..=129).contains(&g) && (126..=129).contains(&b), "{} {}", g, b);
+    assert_eq!(image.get_pixel_color(30, 5), QColor::from_name("white"));
+}
+
+#[test]
+#[cfg(qt_5_8)]
+fn painted_item_config() {
+    #[derive(Default, QObject)]
+    struct ConfiguredPaintedItem {
+        base: qt_base_class!(trait QQuickPaintedItem),
+        color: qt_property!(QString; WRITE set_color),
+    }
+
+    impl ConfiguredPaintedItem {
+        // Only repaints the top of the right half
+        fn set_color(&mut self, color: QString) {
+            self.color = color;
+            let item = self as &dyn QQuickPaintedItem;
+            item.update_rect(QRectF { x: 10., y: 0., width: 10., height: 15. });
+        }
+    }
+
+    impl QQuickItem for ConfiguredPaintedItem {
+        fn component_complete(&mut self) {
+            let item = self as &dyn QQuickPaintedItem;
+            item.set_fill_color(QColor::from_name("blue"));
+            item.set_render_target(RenderTarget::Image);
+            item.set_antialiasing(true);
+            item.set_mipmap(true);
+            item.set_opaque_painting(true);
+            item.set_texture_size(QSize { width: 20, height: 30 });
+            item.set_performance_hint(PerformanceHints::FAST_FBO_RESIZING, true);
+
+            assert_eq!(item.fill_color(), QColor::from_name("blue"));
+            assert_eq!(item.render_target(), RenderTarget::Image);
+            assert!(item.antialiasing());
+            assert!(item.mipmap());
+            assert!(item.opaque_painting());
+            assert_eq!(item.texture_size(), QSize { width: 20, height: 30 });
+            assert!(item.performance_hints().contains(PerformanceHints::FAST_FBO_RESIZING));
+            item.set_performance_hint(PerformanceHints::FAST_FBO_RESIZING, false);
+            assert_eq!(item.performance_hints(), PerformanceHints::default());
+        }
+    }
+
+    impl QQuickPaintedItem for ConfiguredPaintedItem {
+        fn paint(&mut self, p: &mut QPainter) {
+            p.fill_rect(
+                QRectF { x: 10., y: 0., width: 10., height: 30. },
+                QBrush::from_color(QColor::from_name(&self.color.to_string())),
+            );
+        }
+    }
+
+    let _lock = lock_for_test();
+    let mut renderer = item_renderer::<ConfiguredPaintedItem>(QSize { width: 20, height: 30 });
+    let item = renderer.root_object().unwrap().find_child("item".into()).unwrap();
+    assert!(item.set_property("color".into(), QString::from("red").into()));
+
+    let image = renderer.render();
+    assert_eq!(image.get_pixel_color(5, 15), QColor::from_name("blue"));
+    assert_eq!(image.get_pixel_color(15, 5), QColor::from_name("red"));
+    assert_eq!(image.get_pixel_color(15, 25), QColor::from_name("red"));
+
+    assert!(item.set_property("color".into(), QString::from("lime").into()));
+    let image = renderer.render();
+    assert_eq!(image.get_pixel_color(5, 15), QColor::from_name("blue"));
+    assert_eq!(image.get_pixel_color(15, 5), QColor::from_name("lime"));
+    assert_eq!(image.get_pixel_color(15, 25), QColor::from_name("red"));
+}
+
+#[test]
+#[cfg(qt_5_8)]
+fn painter_paths() {
+    #[derive(Default, QObject)]
+    struct PathsPaintedItem {
+        base: qt_base_class!(trait QQuickPaintedItem),
+    }
+
+    impl QQuickItem for PathsPaintedItem {}
+
+    impl QQuickPaintedItem for PathsPaintedItem {
+        fn paint(&mut self, p: &mut QPainter) {
+            let mut font = QFont::default();
+            font.set_pixel_size(30);
+            p.set_font(&font);
+            assert_eq!(p.font().pixel_size(), 30);
+            assert!(p.font_metrics().height() >= 30.);
+
+            // Only the top half is painted
+            p.set_clip_rect(
+                QRectF { x: 0., y: 0., width: 40., height: 10. },
+                ClipOperation::ReplaceClip,
+            );
+            assert!(p.has_clipping());
+
+            let mut path = QPainterPath::default();
+            path.add_rect(QRectF { x: 0., y: 0., width: 20., height: 20. });
+            let mut gradient =
+                QLinearGradient::new(QPointF { x: 0., y: 0. }, QPointF { x: 20., y: 0. });
+            gradient.set_color_at(0., QColor::from_name("red"));
+            gradient.set_color_at(1., QColor::from_name("blue"));
+            p.fill_path(&path, gradient.into());
+
+            p.set_transform(&QTransform::from_translate(20., 0.), true);
+            assert_eq!(p.transform().map(QPointF::default()), QPointF { x: 20., y: 0. });
+            p.set_composition_mode(QPainterCompositionMode::Source);
+            assert_eq!(p.composition_mode(), QPainterCompositionMode::Source);
+            p.fill_path(&path, QBrush::from_color(QColor::from_name("green")));
+        }
+    }
+
+    let image = render_item::<PathsPaintedItem>(QSize { width: 40, height: 20 });
+    let (r, _, b, _) = image.get_pixel_color(1, 5).get_rgba();
+    assert!(r > 200 && b < 60, "{} {}", r, b);
+    let (r, _, b, _) = image.get_pixel_color(18, 5).get_rgba();
+    assert!(r < 60 && b > 200, "{} {}", r, b);
+    assert_eq!(image.get_pixel_color(30, 5), QColor::from_name("green"));
+    assert_eq!(image.get_pixel_color(10, 15), QColor::from_name("white"));
+    assert_eq!(image.get_pixel_color(30, 15), QColor::from_name("white"));
+}
+
+#[test]
+#[cfg(qt_6_7)]
+fn text_node() {
+    use qmetaobject::scenegraph::*;
+
+    #[derive(Default, QObject)]
+    struct TextNodeItem {
+        base: qt_base_class!(trait QQuickItem),
+    }
+
+    impl QQuickItem for TextNodeItem {
+        fn component_complete(&mut self) {
+            (self as &dyn QQuickItem).set_flag(QQuickItemFlags::ITEM_HAS_CONTENTS, true);
+        }
+
+        fn update_paint_node(&mut self, mut node: SGNode<ContainerNode>) -> SGNode<ContainerNode> {
+            let item = self as &dyn QQuickItem;
+            let rect = item.bounding_rect();
+            node.update_static(|mut n: SGNode<TextNode>| -> SGNode<TextNode> {
+                let mut font = QFont::default();
+                font.set_pixel_size(20);
+                n.create(item);
+                n.set_text(
+                    "ii".into(),
+                    &font,
+                    QColor::from_name("black"),
+                    rect,
+                    Alignment::ALIGN_RIGHT | Alignment::ALIGN_V_CENTER,
+                    TextElideMode::ElideRight,
+                );
+                n
+            });
+            node
+        }
+    }
+
+    let image = render_item::<TextNodeItem>(QSize { width: 80, height: 30 });
+    let dark = |x| (0..30).any(|y| image.get_pixel_color(x, y).get_rgba().0 < 128);
+    assert!(!(0..40).any(dark));
+    assert!((40..80).any(dark));
+}
+
+#[test]
+fn fonts() {
+    #[derive(QObject, Default)]
+    struct FontObject {
+        base: qt_base_class!(trait QObject),
+        font: qt_property!(QFont; NOTIFY font_changed),
+        font_changed: qt_signal!(),
+        check_font: qt_method!(
+            fn check_font(&self) -> bool {
+                self.font.pixel_size() == 20
+                    && self.font.bold()
+                    && self.font.family() == QString::from("Sans")
+            }
+        ),
+    }
+
+    let mut obj = FontObject::default();
+    obj.font.set_pixel_size(12);
+    assert!(do_test(
+        obj,
+        r#"
+        Item {
+            function doTest() {
+                if (_obj.font.pixelSize !== 12)
+                    return false;
+                _obj.font = Qt.font({ family: "Sans", pixelSize: 20, bold: true });
+                return _obj.check_font();
+            }
+        }
+        "#
+    ));
+
+    let _lock = lock_for_test();
+    // The font functions need an application
+    let _engine = QmlEngine::new();
+    let mut font = QFont::default();
+    font.set_pixel_size(16);
+    let metrics = QFontMetricsF::new(&font);
+    let advance = metrics.horizontal_advance("Hello".into());
+    assert!(advance > metrics.horizontal_advance("Hell".into()));
+    let rect = metrics.bounding_rect("Hello".into());
+    assert!(rect.width > 0. && rect.y < 0., "{:?}", rect);
+    assert!(metrics.tight_bounding_rect("Hello".into()).height <= rect.height);
+    assert_eq!(
+        metrics.elided_text("Hello".into(), TextElideMode::ElideRight, advance + 1.),
+        QString::from("Hello")
+    );
+    let elided = metrics.elided_text("Hello World".into(), TextElideMode::ElideRight, advance);
+    assert!(elided.to_string().ends_with('\u{2026}'), "{}", elided);
+
+    assert_eq!(QFontDatabase::add_application_font_from_data(b"not a font"), None);
+    assert_eq!(QFontDatabase::add_application_font("/does/not/exist.ttf".into()), None);
+    assert_eq!(QFontDatabase::application_font_families(-1).len(), 0);
+    assert!(!QFontDatabase::remove_application_font(-1));
+
+    let family = QString::from("QmetaobjectTest");
+    let has_family = || QFontDatabase::families().into_iter().any(|f| *f == family);
+    let id =
+        QFontDatabase::add_application_font_from_data(include_bytes!("fonts/QmetaobjectTest.ttf"))
+            .unwrap();
+    let families = QFontDatabase::application_font_families(id);
+    assert_eq!(families.len(), 1);
+    assert_eq!(families[0], family);
+    assert!(has_family());
+    assert!(QFontDatabase::remove_application_font(id));
+    assert!(!QFontDatabase::remove_application_font(id));
+    assert_eq!(QFontDatabase::application_font_families(id).len(), 0);
+
+    qrc!(font_resource, "tests/fonts" { "QmetaobjectTest.ttf" });
+    font_resource();
+    let id =
+        QFontDatabase::add_application_font(":/tests/fonts/QmetaobjectTest.ttf".into()).unwrap();
+    assert_eq!(QFontDatabase::application_font_families(id)[0], family);
+    assert!(QFontDatabase::remove_application_font(id));
+
+    let fixed = QFontDatabase::system_font(SystemFont::FixedFont);
+    assert!(!fixed.family().to_string().is_empty());
+    assert!(fixed.point_size_f() > 0. || fixed.pixel_size() > 0);
+}
@@ -248,6 +248,9 @@ fn main() {
     link_lib("Quick");
     #[cfg(feature = "qtquick")]
     link_lib("Qml");
+    // QtQml depends on it anyway, qmetaobject uses it to customize the network access of QML
+    #[cfg(feature = "qtquick")]
+    link_lib("Network");
     #[cfg(feature = "qtwebengine")]
     if (cargo_target_os == "windows") && (cargo_target_env != "msvc") {
         println!("cargo:warning=On Windows, WebEngine module is only available under MSVC 2017 or MSVC2019.");
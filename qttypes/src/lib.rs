@@ -104,6 +104,7 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 //!   if Qt is not found. Otherwise, when not enabled, the build will continue, but any use of the classes will
 //!   panic at runtime.
 //! - **`chrono`**: enable the conversion between [`QDateTime`] related types and the types from the `chrono` crate.
+//! - **`image`**: enable the conversion between [`QImage`] and `image::RgbaImage` from the `image` crate.
 //!
 //! Link against these Qt modules using cargo features:
 //!
@@ -121,7 +122,7 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 #![cfg_attr(no_qt, allow(unused))]
 
 use std::collections::HashMap;
-use std::convert::From;
+use std::convert::{From, TryFrom};
 use std::fmt;
 use std::hash::Hash;
 use std::iter::FromIterator;
@@ -173,9 +174,14 @@ pub use crate::qtcore::{
 };
 
 mod qtgui;
-pub use crate::qtgui::{QColor, QColorNameFormat, QColorSpec, QRgb, QRgba64};
+pub use crate::qtgui::{
+    FillRule, GradientSpread, QColor, QColorNameFormat, QColorSpec, QConicalGradient, QFont,
+    QFontDatabase, QFontMetricsF, QLinearGradient, QPainterPath, QRadialGradient, QRgb, QRgba64,
+    QTransform, SystemFont,
+};
 
 cpp! {{
+    #include <QtCore/QBuffer>
     #include <QtCore/QByteArray>
     #include <QtCore/QDateTime>
     #include <QtCore/QModelIndex>
@@ -188,6 +194,10 @@ cpp! {{
     #include <QtGui/QPainter>
     #include <QtGui/QPen>
     #include <QtGui/QBrush>
+    #include <QtGui/QFont>
+    #include <QtGui/QFontMetricsF>
+    #include <QtGui/QPainterPath>
+    #include <QtGui/QTransform>
 }}
 
 cpp_class!(
@@ -879,6 +889,274 @@ impl QModelIndex {
     }
 }
 
+/// Bindings for [`Qt::SortOrder`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#SortOrder-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortOrder {
+    AscendingOrder = 0,
+    DescendingOrder = 1,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        SortOrder::AscendingOrder
+    }
+}
+
+/// Bindings for [`Qt::Orientation`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#Orientation-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Orientation {
+    Horizontal = 0x1,
+    Vertical = 0x2,
+}
+
+/// Bindings for [`Qt::ItemFlags`][enum] flags.
+///
+/// The flags can be combined with the `|` operator.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#ItemFlag-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ItemFlags(pub i32);
+
+impl ItemFlags {
+    pub const NO_ITEM_FLAGS: ItemFlags = ItemFlags(0);
+    pub const ITEM_IS_SELECTABLE: ItemFlags = ItemFlags(1);
+    pub const ITEM_IS_EDITABLE: ItemFlags = ItemFlags(2);
+    pub const ITEM_IS_DRAG_ENABLED: ItemFlags = ItemFlags(4);
+    pub const ITEM_IS_DROP_ENABLED: ItemFlags = ItemFlags(8);
+    pub const ITEM_IS_USER_CHECKABLE: ItemFlags = ItemFlags(16);
+    pub const ITEM_IS_ENABLED: ItemFlags = ItemFlags(32);
+    pub const ITEM_IS_AUTO_TRISTATE: ItemFlags = ItemFlags(64);
+    pub const ITEM_NEVER_HAS_CHILDREN: ItemFlags = ItemFlags(128);
+    pub const ITEM_IS_USER_TRISTATE: ItemFlags = ItemFlags(256);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: ItemFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for ItemFlags {
+    type Output = ItemFlags;
+    fn bitor(self, other: ItemFlags) -> ItemFlags {
+        ItemFlags(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ItemFlags {
+    fn bitor_assign(&mut self, other: ItemFlags) {
+        self.0 |= other.0;
+    }
+}
+
+#[test]
+fn test_item_flags() {
+    let mut flags = ItemFlags::ITEM_IS_SELECTABLE | ItemFlags::ITEM_IS_ENABLED;
+    assert!(flags.contains(ItemFlags::ITEM_IS_ENABLED));
+    assert!(!flags.contains(ItemFlags::ITEM_IS_EDITABLE));
+    flags |= ItemFlags::ITEM_IS_EDITABLE;
+    assert!(flags.contains(ItemFlags::ITEM_IS_EDITABLE | ItemFlags::ITEM_IS_SELECTABLE));
+    assert_eq!(flags, ItemFlags(35));
+}
+
+/// Bindings for [`Qt::KeyboardModifiers`][enum] flags.
+///
+/// The flags can be combined with the `|` operator.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#KeyboardModifier-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct KeyboardModifiers(pub i32);
+
+impl KeyboardModifiers {
+    pub const NO_MODIFIER: KeyboardModifiers = KeyboardModifiers(0);
+    pub const SHIFT_MODIFIER: KeyboardModifiers = KeyboardModifiers(0x02000000);
+    /// The Control key, or the Command key on macOS
+    pub const CONTROL_MODIFIER: KeyboardModifiers = KeyboardModifiers(0x04000000);
+    pub const ALT_MODIFIER: KeyboardModifiers = KeyboardModifiers(0x08000000);
+    /// The Meta key, or the Control key on macOS
+    pub const META_MODIFIER: KeyboardModifiers = KeyboardModifiers(0x10000000);
+    pub const KEYPAD_MODIFIER: KeyboardModifiers = KeyboardModifiers(0x20000000);
+    pub const GROUP_SWITCH_MODIFIER: KeyboardModifiers = KeyboardModifiers(0x40000000);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: KeyboardModifiers) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for KeyboardModifiers {
+    type Output = KeyboardModifiers;
+    fn bitor(self, other: KeyboardModifiers) -> KeyboardModifiers {
+        KeyboardModifiers(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for KeyboardModifiers {
+    fn bitor_assign(&mut self, other: KeyboardModifiers) {
+        self.0 |= other.0;
+    }
+}
+
+/// Bindings for [`Qt::MouseButtons`][enum] flags.
+///
+/// The flags can be combined with the `|` operator.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#MouseButton-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct MouseButtons(pub i32);
+
+impl MouseButtons {
+    pub const NO_BUTTON: MouseButtons = MouseButtons(0);
+    pub const LEFT_BUTTON: MouseButtons = MouseButtons(0x01);
+    pub const RIGHT_BUTTON: MouseButtons = MouseButtons(0x02);
+    pub const MIDDLE_BUTTON: MouseButtons = MouseButtons(0x04);
+    pub const BACK_BUTTON: MouseButtons = MouseButtons(0x08);
+    pub const FORWARD_BUTTON: MouseButtons = MouseButtons(0x10);
+    pub const ALL_BUTTONS: MouseButtons = MouseButtons(0x07ffffff);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: MouseButtons) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for MouseButtons {
+    type Output = MouseButtons;
+    fn bitor(self, other: MouseButtons) -> MouseButtons {
+        MouseButtons(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for MouseButtons {
+    fn bitor_assign(&mut self, other: MouseButtons) {
+        self.0 |= other.0;
+    }
+}
+
+#[test]
+fn test_input_flags() {
+    let modifiers = KeyboardModifiers::CONTROL_MODIFIER | KeyboardModifiers::SHIFT_MODIFIER;
+    assert!(modifiers.contains(KeyboardModifiers::SHIFT_MODIFIER));
+    assert!(!modifiers.contains(KeyboardModifiers::ALT_MODIFIER));
+    assert!(modifiers.contains(KeyboardModifiers::NO_MODIFIER));
+    let mut buttons = MouseButtons::LEFT_BUTTON;
+    buttons |= MouseButtons::RIGHT_BUTTON;
+    assert_eq!(buttons, MouseButtons(3));
+    assert!(MouseButtons::ALL_BUTTONS.contains(buttons | MouseButtons::FORWARD_BUTTON));
+}
+
+/// Bindings for [`Qt::FocusReason`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#FocusReason-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FocusReason {
+    MouseFocusReason = 0,
+    TabFocusReason = 1,
+    BacktabFocusReason = 2,
+    ActiveWindowFocusReason = 3,
+    PopupFocusReason = 4,
+    ShortcutFocusReason = 5,
+    MenuBarFocusReason = 6,
+    OtherFocusReason = 7,
+    NoFocusReason = 8,
+}
+
+/// Bindings for [`Qt::CursorShape`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#CursorShape-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CursorShape {
+    ArrowCursor = 0,
+    UpArrowCursor = 1,
+    CrossCursor = 2,
+    WaitCursor = 3,
+    IBeamCursor = 4,
+    SizeVerCursor = 5,
+    SizeHorCursor = 6,
+    SizeBDiagCursor = 7,
+    SizeFDiagCursor = 8,
+    SizeAllCursor = 9,
+    BlankCursor = 10,
+    SplitVCursor = 11,
+    SplitHCursor = 12,
+    PointingHandCursor = 13,
+    ForbiddenCursor = 14,
+    WhatsThisCursor = 15,
+    BusyCursor = 16,
+    OpenHandCursor = 17,
+    ClosedHandCursor = 18,
+    DragCopyCursor = 19,
+    DragMoveCursor = 20,
+    DragLinkCursor = 21,
+}
+
+impl Default for CursorShape {
+    fn default() -> Self {
+        CursorShape::ArrowCursor
+    }
+}
+
+/// Bindings for [`Qt::Alignment`][enum] flags.
+///
+/// The flags can be combined with the `|` operator, for example
+/// `Alignment::ALIGN_RIGHT | Alignment::ALIGN_V_CENTER`.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#AlignmentFlag-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Alignment(pub i32);
+
+impl Alignment {
+    pub const ALIGN_LEFT: Alignment = Alignment(0x0001);
+    pub const ALIGN_RIGHT: Alignment = Alignment(0x0002);
+    pub const ALIGN_H_CENTER: Alignment = Alignment(0x0004);
+    pub const ALIGN_JUSTIFY: Alignment = Alignment(0x0008);
+    pub const ALIGN_TOP: Alignment = Alignment(0x0020);
+    pub const ALIGN_BOTTOM: Alignment = Alignment(0x0040);
+    pub const ALIGN_V_CENTER: Alignment = Alignment(0x0080);
+    pub const ALIGN_CENTER: Alignment = Alignment(0x0084);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: Alignment) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for Alignment {
+    type Output = Alignment;
+    fn bitor(self, other: Alignment) -> Alignment {
+        Alignment(self.0 | other.0)
+    }
+}
+
+/// Bindings for [`Qt::TextElideMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#TextElideMode-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextElideMode {
+    ElideLeft = 0,
+    ElideRight = 1,
+    ElideMiddle = 2,
+    ElideNone = 3,
+}
+
+impl Default for TextElideMode {
+    fn default() -> Self {
+        TextElideMode::ElideNone
+    }
+}
+
 /// Bindings for [`QRectF`][class] class.
 ///
 /// [class]: https://doc.qt.io/qt-5/qrectf.html
@@ -1094,6 +1372,362 @@ impl QImage {
             return self->pixelColor(x, y);
         })
     }
+
+    /// Wrapper around [`save(const QString &fileName, const char *format = nullptr, int quality = -1)`][method] method.
+    ///
+    /// If `format` is None, the format is deduced from the file name suffix (e.g. `"PNG"` for `.png`).
+    /// Returns false if the image could not be written.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#save
+    pub fn save(&self, filename: QString, format: Option<&str>) -> bool {
+        let format = QByteArray::from(format.unwrap_or_default());
+        cpp!(unsafe [self as "const QImage*", filename as "QString", format as "QByteArray"] -> bool as "bool" {
+            return self->save(filename, format.isEmpty() ? nullptr : format.constData());
+        })
+    }
+
+    /// Returns the image encoded as PNG.
+    ///
+    /// Returns an empty vector if the image is null.
+    pub fn to_png_bytes(&self) -> Vec<u8> {
+        self.save_to_buffer("PNG")
+    }
+
+    /// Wrapper around [`save(QIODevice *device, const char *format = nullptr, int quality = -1)`][method] method,
+    /// with a QBuffer.
+    ///
+    /// Returns the image encoded in the given format (e.g. `"PNG"` or `"JPG"`), or an empty
+    /// vector if the image is null or the format is not supported.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#save-1
+    pub fn save_to_buffer(&self, format: &str) -> Vec<u8> {
+        let format = QByteArray::from(format);
+        let bytes = cpp!(unsafe [self as "const QImage*", format as "QByteArray"] -> QByteArray as "QByteArray" {
+            QByteArray bytes;
+            QBuffer buffer(&bytes);
+            buffer.open(QIODevice::WriteOnly);
+            if (!self->save(&buffer, format.constData()))
+                return QByteArray();
+            return bytes;
+        });
+        bytes.to_slice().to_vec()
+    }
+
+    /// Wrapper around [`fromData(const uchar *data, int size, const char *format = nullptr)`][method] method.
+    ///
+    /// If `format` is None, the format is detected from the content. Returns a null image if
+    /// the data could not be decoded.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#fromData
+    pub fn load_from_data(data: &[u8], format: Option<&str>) -> Self {
+        let data_ptr = data.as_ptr();
+        let data_len = data.len();
+        let format = QByteArray::from(format.unwrap_or_default());
+        cpp!(unsafe [data_ptr as "const uchar*", data_len as "size_t", format as "QByteArray"] -> QImage as "QImage" {
+            return QImage::fromData(data_ptr, int(data_len), format.isEmpty() ? nullptr : format.constData());
+        })
+    }
+
+    /// Creates an image with a copy of the pixels in `data`.
+    ///
+    /// `data` must hold `height` lines of `bytes_per_line` bytes, in the given format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_line` is too small for `width` pixels, if `data` is too short, or if
+    /// the sizes do not fit in an `int`.
+    pub fn from_raw(
+        data: &[u8],
+        width: u32,
+        height: u32,
+        bytes_per_line: usize,
+        format: ImageFormat,
+    ) -> Self {
+        let width = i32::try_from(width).expect("the image is too wide");
+        let height = i32::try_from(height).expect("the image is too high");
+        let bytes_per_line_int =
+            i32::try_from(bytes_per_line).expect("bytes_per_line is too large");
+        let bits_per_pixel = cpp!(unsafe [format as "QImage::Format"] -> u32 as "uint" {
+            return QImage::toPixelFormat(format).bitsPerPixel();
+        });
+        let min_bytes_per_line = (width as u64 * bits_per_pixel as u64 + 7) / 8;
+        assert!(
+            bytes_per_line as u64 >= min_bytes_per_line,
+            "bytes_per_line is too small for the width of the image"
+        );
+        let size = bytes_per_line.checked_mul(height as usize).expect("the image is too large");
+        assert!(data.len() >= size, "data is too short for the image");
+        let data_ptr = data.as_ptr();
+        cpp!(unsafe [data_ptr as "const uchar*", width as "int", height as "int", bytes_per_line_int as "int", format as "QImage::Format"] -> QImage as "QImage" {
+            return QImage(data_ptr, width, height, bytes_per_line_int, format).copy();
+        })
+    }
+
+    /// Wrapper around [`isNull()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#isNull
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QImage*"] -> bool as "bool" { return self->isNull(); })
+    }
+
+    /// Wrapper around [`bytesPerLine()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#bytesPerLine
+    pub fn bytes_per_line(&self) -> usize {
+        cpp!(unsafe [self as "const QImage*"] -> usize as "size_t" { return self->bytesPerLine(); })
+    }
+
+    /// Wrapper around [`constBits()`][method] method.
+    ///
+    /// Returns all the pixel data, which is `height` lines of `bytes_per_line()` bytes.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#constBits
+    pub fn bits(&self) -> &[u8] {
+        unsafe {
+            let len = self.size_in_bytes();
+            if len == 0 {
+                return &[];
+            }
+            let ptr = cpp!([self as "const QImage*"] -> *const u8 as "const uchar*" {
+                return self->constBits();
+            });
+            std::slice::from_raw_parts(ptr, len)
+        }
+    }
+
+    /// Wrapper around [`bits()`][method] method.
+    ///
+    /// Like `bits`, but mutable. The image data is detached if it was shared.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#bits
+    pub fn bits_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            let len = self.size_in_bytes();
+            if len == 0 {
+                return &mut [];
+            }
+            let ptr = cpp!([self as "QImage*"] -> *mut u8 as "uchar*" {
+                return self->bits();
+            });
+            std::slice::from_raw_parts_mut(ptr, len)
+        }
+    }
+
+    /// Wrapper around [`constScanLine(int i)`][method] method.
+    ///
+    /// Returns the `bytes_per_line()` bytes of the line `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is out of the image.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#constScanLine
+    pub fn scan_line(&self, y: u32) -> &[u8] {
+        let bytes_per_line = self.bytes_per_line();
+        let start = y as usize * bytes_per_line;
+        &self.bits()[start..start + bytes_per_line]
+    }
+
+    /// Wrapper around [`scanLine(int i)`][method] method.
+    ///
+    /// Like `scan_line`, but mutable.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#scanLine
+    pub fn scan_line_mut(&mut self, y: u32) -> &mut [u8] {
+        let bytes_per_line = self.bytes_per_line();
+        let start = y as usize * bytes_per_line;
+        &mut self.bits_mut()[start..start + bytes_per_line]
+    }
+
+    fn size_in_bytes(&self) -> usize {
+        cpp!(unsafe [self as "const QImage*"] -> usize as "size_t" {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 10, 0)
+            return self->sizeInBytes();
+            #else
+            return self->byteCount();
+            #endif
+        })
+    }
+
+    /// Wrapper around [`convertToFormat(QImage::Format format)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#convertToFormat
+    pub fn convert_to_format(&self, format: ImageFormat) -> QImage {
+        cpp!(unsafe [self as "const QImage*", format as "QImage::Format"] -> QImage as "QImage" {
+            return self->convertToFormat(format);
+        })
+    }
+
+    /// Wrapper around [`scaled(const QSize &size, Qt::AspectRatioMode aspectRatioMode, Qt::TransformationMode transformMode)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#scaled
+    pub fn scaled(
+        &self,
+        size: QSize,
+        aspect_ratio_mode: AspectRatioMode,
+        transform_mode: TransformationMode,
+    ) -> QImage {
+        cpp!(unsafe [self as "const QImage*", size as "QSize", aspect_ratio_mode as "Qt::AspectRatioMode", transform_mode as "Qt::TransformationMode"] -> QImage as "QImage" {
+            return self->scaled(size, aspect_ratio_mode, transform_mode);
+        })
+    }
+
+    /// Wrapper around [`copy(const QRect &rectangle)`][method] method.
+    ///
+    /// The rectangle is rounded to integer coordinates. The parts of the rectangle outside of
+    /// the image are filled with zeros.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qimage.html#copy
+    pub fn copy(&self, rectangle: QRectF) -> QImage {
+        cpp!(unsafe [self as "const QImage*", rectangle as "QRectF"] -> QImage as "QImage" {
+            return self->copy(rectangle.toRect());
+        })
+    }
+}
+
+/// Bindings for [`Qt::AspectRatioMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#AspectRatioMode-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AspectRatioMode {
+    IgnoreAspectRatio = 0,
+    KeepAspectRatio = 1,
+    KeepAspectRatioByExpanding = 2,
+}
+
+/// Bindings for [`Qt::TransformationMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#TransformationMode-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransformationMode {
+    FastTransformation = 0,
+    SmoothTransformation = 1,
+}
+
+#[cfg(feature = "image")]
+impl From<&QImage> for image::RgbaImage {
+    /// Converts the image to non-premultiplied RGBA, and copies the pixels.
+    fn from(qimage: &QImage) -> Self {
+        let converted = qimage.convert_to_format(ImageFormat::RGBA8888);
+        let QSize { width, height } = converted.size();
+        let row_len = width as usize * 4;
+        let mut data = Vec::with_capacity(row_len * height as usize);
+        for y in 0..height {
+            data.extend_from_slice(&converted.scan_line(y)[..row_len]);
+        }
+        image::RgbaImage::from_raw(width, height, data).unwrap()
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::RgbaImage> for QImage {
+    /// Wraps the pixel buffer of the image, without copying it.
+    fn from(rgba_image: image::RgbaImage) -> Self {
+        extern "C" fn free_buffer(info: *mut std::os::raw::c_void) {
+            drop(unsafe { Box::from_raw(info as *mut Vec<u8>) });
+        }
+        let (width, height) = rgba_image.dimensions();
+        if width == 0 || height == 0 {
+            return QImage::default();
+        }
+        let height = i32::try_from(height).expect("the image is too high");
+        let bytes_per_line = i32::try_from(width)
+            .ok()
+            .and_then(|width| width.checked_mul(4))
+            .expect("the image is too wide");
+        let width = width as i32;
+        let mut buffer = Box::new(rgba_image.into_raw());
+        let data_ptr = buffer.as_mut_ptr();
+        let info = Box::into_raw(buffer) as *mut std::os::raw::c_void;
+        let cleanup = free_buffer as extern "C" fn(*mut std::os::raw::c_void);
+        cpp!(unsafe [data_ptr as "uchar*", width as "int", height as "int", bytes_per_line as "int", cleanup as "QImageCleanupFunction", info as "void*"] -> QImage as "QImage" {
+            return QImage(data_ptr, width, height, bytes_per_line, QImage::Format_RGBA8888, cleanup, info);
+        })
+    }
+}
+
+#[test]
+fn test_qimage_from_raw_checks() {
+    let panics = |data: &'static [u8], width, height, bytes_per_line| {
+        std::panic::catch_unwind(move || {
+            QImage::from_raw(data, width, height, bytes_per_line, ImageFormat::RGB32)
+        })
+        .is_err()
+    };
+    assert!(panics(&[], 10, 10, 0));
+    assert!(panics(&[0; 400], 10, 10, 39));
+    assert!(panics(&[0; 400], 10, 11, 40));
+    assert!(panics(&[], u32::MAX, 1, 0));
+    assert!(panics(&[], 1, 1, usize::MAX));
+    assert!(!panics(&[0; 400], 10, 10, 40));
+}
+
+#[test]
+fn test_qimage_pixels() {
+    // 3x2 RGB888 image with lines padded to 12 bytes
+    let mut data = vec![0u8; 24];
+    data[0..3].copy_from_slice(&[255, 0, 0]);
+    data[12 + 6..12 + 9].copy_from_slice(&[0, 0, 255]);
+    let mut image = QImage::from_raw(&data, 3, 2, 12, ImageFormat::RGB888);
+    data[0] = 0; // the image holds a copy
+    assert!(!image.is_null());
+    assert_eq!(image.size(), QSize { width: 3, height: 2 });
+    assert_eq!(image.get_pixel_color(0, 0), QColor::from_name("red"));
+    assert_eq!(image.get_pixel_color(2, 1), QColor::from_name("blue"));
+    assert_eq!(image.bits().len(), image.bytes_per_line() * 2);
+    assert_eq!(&image.scan_line(1)[6..9], &[0, 0, 255]);
+
+    let shared = image.clone();
+    image.scan_line_mut(0)[3..6].copy_from_slice(&[0, 255, 0]);
+    assert_eq!(image.get_pixel_color(1, 0), QColor::from_rgb(0, 255, 0));
+    assert_eq!(shared.get_pixel_color(1, 0), QColor::from_name("black"));
+
+    let argb = image.convert_to_format(ImageFormat::ARGB32);
+    assert_eq!(argb.format(), ImageFormat::ARGB32);
+    assert_eq!(argb.bytes_per_line(), 12);
+    assert_eq!(argb.get_pixel_color(2, 1), QColor::from_name("blue"));
+
+    let scaled = image.scaled(
+        QSize { width: 6, height: 6 },
+        AspectRatioMode::KeepAspectRatio,
+        TransformationMode::FastTransformation,
+    );
+    assert_eq!(scaled.size(), QSize { width: 6, height: 4 });
+    assert_eq!(scaled.get_pixel_color(5, 3), QColor::from_name("blue"));
+
+    let copy = image.copy(QRectF { x: 1., y: 1., width: 2., height: 1. });
+    assert_eq!(copy.size(), QSize { width: 2, height: 1 });
+    assert_eq!(copy.get_pixel_color(1, 0), QColor::from_name("blue"));
+
+    let png = image.save_to_buffer("PNG");
+    assert_eq!(png, image.to_png_bytes());
+    let decoded = QImage::load_from_data(&png, None);
+    assert_eq!(decoded.size(), image.size());
+    assert_eq!(decoded.get_pixel_color(1, 0), QColor::from_rgb(0, 255, 0));
+    assert!(QImage::load_from_data(&png, Some("PNG")).size() == image.size());
+    assert!(QImage::load_from_data(b"garbage", None).is_null());
+    assert!(image.save_to_buffer("NOT_A_FORMAT").is_empty());
+    assert!(QImage::default().bits().is_empty());
+}
+
+#[test]
+#[cfg(feature = "image")]
+fn test_qimage_rgba_image() {
+    let mut rgba = image::RgbaImage::new(3, 2);
+    rgba.put_pixel(2, 1, image::Rgba([0, 0, 255, 128]));
+    let qimage = QImage::from(rgba.clone());
+    assert_eq!(qimage.format(), ImageFormat::RGBA8888);
+    assert_eq!(qimage.size(), QSize { width: 3, height: 2 });
+    assert_eq!(qimage.get_pixel_color(2, 1), QColor::from_rgba(0, 0, 255, 128));
+    assert_eq!(image::RgbaImage::from(&qimage), rgba);
+
+    let mut argb = QImage::new(QSize { width: 2, height: 1 }, ImageFormat::ARGB32_Premultiplied);
+    argb.fill(QColor::from_name("red"));
+    let converted = image::RgbaImage::from(&argb);
+    assert_eq!(converted.get_pixel(1, 0), &image::Rgba([255, 0, 0, 255]));
+    assert!(QImage::from(image::RgbaImage::new(0, 0)).is_null());
 }
 
 cpp_class!(
@@ -1297,6 +1931,43 @@ cpp_class!(
     pub unsafe struct QPainter as "QPainter "
 );
 impl QPainter {
+    /// Paints on `image` with a painter, within the scope of `f`.
+    ///
+    /// The painter begins on the image before `f` is called, and ends when `f` returns.
+    /// If the image is null, the painter is not active and draws nothing.
+    ///
+    /// ```no_run
+    /// use qttypes::{ImageFormat, QColor, QImage, QPainter, QRectF, QSize};
+    ///
+    /// let mut image = QImage::new(QSize { width: 20, height: 20 }, ImageFormat::ARGB32);
+    /// QPainter::on_image(&mut image, |painter| {
+    ///     painter.draw_rect(QRectF { x: 0., y: 0., width: 10., height: 10. });
+    /// });
+    /// ```
+    pub fn on_image<R>(image: &mut QImage, f: impl FnOnce(&mut QPainter) -> R) -> R {
+        struct PainterGuard(*mut QPainter);
+        impl Drop for PainterGuard {
+            fn drop(&mut self) {
+                let painter = self.0;
+                cpp!(unsafe [painter as "QPainter *"] { delete painter; });
+            }
+        }
+        let painter = cpp!(unsafe [image as "QImage *"] -> *mut QPainter as "QPainter *" {
+            return new QPainter(image);
+        });
+        let guard = PainterGuard(painter);
+        f(unsafe { &mut *guard.0 })
+    }
+
+    /// Wrapper around [`isActive()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainter.html#isActive
+    pub fn is_active(&self) -> bool {
+        cpp!(unsafe [self as "const QPainter *"] -> bool as "bool" {
+            return self->isActive();
+        })
+    }
+
     pub fn draw_arc(&mut self, rectangle: QRectF, start_angle: i32, span_angle: i32) {
         cpp!(unsafe [self as "QPainter *", rectangle as "QRectF", start_angle as "int", span_angle as "int"] {
             self->drawArc(rectangle, start_angle, span_angle);
@@ -1520,9 +2191,141 @@ impl QPainter {
         });
     }
 
+    pub fn draw_path(&mut self, path: &QPainterPath) {
+        cpp!(unsafe [self as "QPainter *", path as "const QPainterPath *"] {
+            self->drawPath(*path);
+        });
+    }
+    pub fn fill_path(&mut self, path: &QPainterPath, brush: QBrush) {
+        cpp!(unsafe [self as "QPainter *", path as "const QPainterPath *", brush as "QBrush"] {
+            self->fillPath(*path, brush);
+        });
+    }
+    pub fn stroke_path(&mut self, path: &QPainterPath, pen: QPen) {
+        cpp!(unsafe [self as "QPainter *", path as "const QPainterPath *", pen as "QPen"] {
+            self->strokePath(*path, pen);
+        });
+    }
+
+    pub fn transform(&self) -> QTransform {
+        cpp!(unsafe [self as "const QPainter *"] -> QTransform as "QTransform" {
+            return self->transform();
+        })
+    }
+    /// Replaces the current transform, or combines it with the current one if `combine` is true.
+    pub fn set_transform(&mut self, transform: &QTransform, combine: bool) {
+        cpp!(unsafe [self as "QPainter *", transform as "const QTransform *", combine as "bool"] {
+            self->setTransform(*transform, combine);
+        });
+    }
+
+    pub fn set_clip_rect(&mut self, rectangle: QRectF, operation: ClipOperation) {
+        cpp!(unsafe [self as "QPainter *", rectangle as "QRectF", operation as "Qt::ClipOperation"] {
+            self->setClipRect(rectangle, operation);
+        });
+    }
+    pub fn set_clip_path(&mut self, path: &QPainterPath, operation: ClipOperation) {
+        cpp!(unsafe [self as "QPainter *", path as "const QPainterPath *", operation as "Qt::ClipOperation"] {
+            self->setClipPath(*path, operation);
+        });
+    }
+    pub fn set_clipping(&mut self, enable: bool) {
+        cpp!(unsafe [self as "QPainter *", enable as "bool"] {
+            self->setClipping(enable);
+        });
+    }
+    pub fn has_clipping(&self) -> bool {
+        cpp!(unsafe [self as "const QPainter *"] -> bool as "bool" {
+            return self->hasClipping();
+        })
+    }
+
+    pub fn composition_mode(&self) -> QPainterCompositionMode {
+        cpp!(unsafe [self as "const QPainter *"] -> QPainterCompositionMode as "QPainter::CompositionMode" {
+            return self->compositionMode();
+        })
+    }
+    pub fn set_composition_mode(&mut self, mode: QPainterCompositionMode) {
+        cpp!(unsafe [self as "QPainter *", mode as "QPainter::CompositionMode"] {
+            self->setCompositionMode(mode);
+        });
+    }
+
+    pub fn font(&self) -> QFont {
+        cpp!(unsafe [self as "const QPainter *"] -> QFont as "QFont" {
+            return self->font();
+        })
+    }
+    pub fn set_font(&mut self, font: &QFont) {
+        cpp!(unsafe [self as "QPainter *", font as "const QFont *"] {
+            self->setFont(*font);
+        });
+    }
+    /// Returns the metrics of the current font.
+    pub fn font_metrics(&self) -> QFontMetricsF {
+        cpp!(unsafe [self as "const QPainter *"] -> QFontMetricsF as "QFontMetricsF" {
+            return QFontMetricsF(self->font(), self->device());
+        })
+    }
+
     // void	setBackgroundMode(Qt::BGMode mode)
-    // void	setCompositionMode(QPainter::CompositionMode mode)
-    // void	setFont(const QFont &font)
+}
+
+/// Bindings for [`Qt::ClipOperation`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#ClipOperation-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ClipOperation {
+    NoClip = 0,
+    ReplaceClip = 1,
+    IntersectClip = 2,
+}
+
+/// Bindings for [`QPainter::CompositionMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qpainter.html#CompositionMode-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QPainterCompositionMode {
+    SourceOver = 0,
+    DestinationOver = 1,
+    Clear = 2,
+    Source = 3,
+    Destination = 4,
+    SourceIn = 5,
+    DestinationIn = 6,
+    SourceOut = 7,
+    DestinationOut = 8,
+    SourceAtop = 9,
+    DestinationAtop = 10,
+    Xor = 11,
+    Plus = 12,
+    Multiply = 13,
+    Screen = 14,
+    Overlay = 15,
+    Darken = 16,
+    Lighten = 17,
+    ColorDodge = 18,
+    ColorBurn = 19,
+    HardLight = 20,
+    SoftLight = 21,
+    Difference = 22,
+    Exclusion = 23,
+    RasterOpSourceOrDestination = 24,
+    RasterOpSourceAndDestination = 25,
+    RasterOpSourceXorDestination = 26,
+    RasterOpNotSourceAndNotDestination = 27,
+    RasterOpNotSourceOrNotDestination = 28,
+    RasterOpNotSourceXorDestination = 29,
+    RasterOpNotSource = 30,
+    RasterOpNotSourceAndDestination = 31,
+    RasterOpSourceAndNotDestination = 32,
+    RasterOpNotSourceOrDestination = 33,
+    RasterOpSourceOrNotDestination = 34,
+    RasterOpClearDestination = 35,
+    RasterOpSetDestination = 36,
+    RasterOpNotDestination = 37,
 }
 
 /// Bindings for [`QPainter::RenderHint`][enum] enum.
@@ -1541,6 +2344,35 @@ pub enum QPainterRenderHint {
     LosslessImageRendering = 0x40,
 }
 
+#[test]
+fn test_qpainter_on_image() {
+    let mut image = QImage::new(QSize { width: 20, height: 10 }, ImageFormat::ARGB32);
+    image.fill(QColor::from_name("white"));
+    let active = QPainter::on_image(&mut image, |painter| {
+        painter.fill_rect(
+            QRectF { x: 0., y: 0., width: 10., height: 10. },
+            QBrush::from_color(QColor::from_name("red")),
+        );
+        painter.is_active()
+    });
+    assert!(active);
+    assert_eq!(image.get_pixel_color(5, 5), QColor::from_name("red"));
+    assert_eq!(image.get_pixel_color(15, 5), QColor::from_name("white"));
+
+    let png = image.to_png_bytes();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    assert!(QImage::default().to_png_bytes().is_empty());
+    assert!(!QPainter::on_image(&mut QImage::default(), |painter| painter.is_active()));
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = QString::from(temp_dir.path().join("image.png").to_str().unwrap());
+    assert!(image.save(path.clone(), None));
+    let loaded = QImage::load_from_file(path.clone());
+    assert_eq!(loaded.size(), image.size());
+    assert_eq!(loaded.get_pixel_color(5, 5), QColor::from_name("red"));
+    assert!(image.save(path, Some("PNG")));
+}
+
 cpp! {{
     #include <QtCore/QJsonDocument>
     #include <QtCore/QJsonValue>
@@ -10,7 +10,7 @@ cpp_class!(
     /// Wrapper around [`QUrl`][class] class.
     ///
     /// [class]: https://doc.qt.io/qt-5/qurl.html
-    #[derive(PartialEq, PartialOrd, Eq, Ord)]
+    #[derive(Clone, Default, PartialEq, PartialOrd, Eq, Ord)]
     pub unsafe struct QUrl as "QUrl"
 );
 impl QUrl {
@@ -22,6 +22,33 @@ impl QUrl {
             return QUrl::fromUserInput(user_input);
         })
     }
+
+    /// Wrapper around [`QUrl::scheme()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qurl.html#scheme
+    pub fn scheme(&self) -> QString {
+        cpp!(unsafe [self as "const QUrl *"] -> QString as "QString" {
+            return self->scheme();
+        })
+    }
+
+    /// Wrapper around [`QUrl::host()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qurl.html#host
+    pub fn host(&self) -> QString {
+        cpp!(unsafe [self as "const QUrl *"] -> QString as "QString" {
+            return self->host();
+        })
+    }
+
+    /// Wrapper around [`QUrl::path()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qurl.html#path
+    pub fn path(&self) -> QString {
+        cpp!(unsafe [self as "const QUrl *"] -> QString as "QString" {
+            return self->path();
+        })
+    }
 }
 impl From<QString> for QUrl {
     fn from(qstring: QString) -> QUrl {
@@ -30,3 +57,13 @@ impl From<QString> for QUrl {
         })
     }
 }
+
+#[test]
+fn test_qurl_parts() {
+    let url = QUrl::from(QString::from("app://host/dir/file.qml"));
+    assert_eq!(url.scheme(), QString::from("app"));
+    assert_eq!(url.host(), QString::from("host"));
+    assert_eq!(url.path(), QString::from("/dir/file.qml"));
+    assert!(url.clone() == url);
+    assert_eq!(QUrl::default().scheme(), QString::default());
+}
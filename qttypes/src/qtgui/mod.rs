@@ -1,2 +1,12 @@
 mod qcolor;
 pub use self::qcolor::{QColor, QColorNameFormat, QColorSpec, QRgb, QRgba64};
+mod qfont;
+pub use self::qfont::{QFont, QFontMetricsF};
+mod qfontdatabase;
+pub use self::qfontdatabase::{QFontDatabase, SystemFont};
+mod qgradient;
+pub use self::qgradient::{GradientSpread, QConicalGradient, QLinearGradient, QRadialGradient};
+mod qpainterpath;
+pub use self::qpainterpath::{FillRule, QPainterPath};
+mod qtransform;
+pub use self::qtransform::QTransform;
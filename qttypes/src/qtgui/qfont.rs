@@ -0,0 +1,301 @@
+use crate::internal_prelude::*;
+use crate::{qreal, QRectF, QString, TextElideMode};
+
+cpp! {{
+    #include <QtGui/QFont>
+    #include <QtGui/QFontMetricsF>
+    #include <QtCore/QString>
+}}
+
+cpp_class!(
+    /// Wrapper around [`QFont`][class] class.
+    ///
+    /// The default font is the default font of the application.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qfont.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QFont as "QFont"
+);
+
+impl QFont {
+    /// Wrapper around [`QFont(const QString &family, int pointSize = -1, ...)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qfont.html#QFont-1
+    pub fn from_family(family: QString) -> Self {
+        cpp!(unsafe [family as "QString"] -> QFont as "QFont" {
+            return QFont(family);
+        })
+    }
+
+    /// Wrapper around [`family()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#family
+    pub fn family(&self) -> QString {
+        cpp!(unsafe [self as "const QFont*"] -> QString as "QString" { return self->family(); })
+    }
+
+    /// Wrapper around [`setFamily(const QString &family)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setFamily
+    pub fn set_family(&mut self, family: QString) {
+        cpp!(unsafe [self as "QFont*", family as "QString"] { self->setFamily(family); })
+    }
+
+    /// Wrapper around [`pointSizeF()`][method] method.
+    ///
+    /// Returns -1 if the size was set in pixels.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#pointSizeF
+    pub fn point_size_f(&self) -> qreal {
+        cpp!(unsafe [self as "const QFont*"] -> qreal as "qreal" { return self->pointSizeF(); })
+    }
+
+    /// Wrapper around [`setPointSizeF(qreal pointSize)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setPointSizeF
+    pub fn set_point_size_f(&mut self, size: qreal) {
+        cpp!(unsafe [self as "QFont*", size as "qreal"] { self->setPointSizeF(size); })
+    }
+
+    /// Wrapper around [`pixelSize()`][method] method.
+    ///
+    /// Returns -1 if the size was set in points.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#pixelSize
+    pub fn pixel_size(&self) -> i32 {
+        cpp!(unsafe [self as "const QFont*"] -> i32 as "int" { return self->pixelSize(); })
+    }
+
+    /// Wrapper around [`setPixelSize(int pixelSize)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setPixelSize
+    pub fn set_pixel_size(&mut self, size: i32) {
+        cpp!(unsafe [self as "QFont*", size as "int"] { self->setPixelSize(size); })
+    }
+
+    /// Wrapper around [`bold()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#bold
+    pub fn bold(&self) -> bool {
+        cpp!(unsafe [self as "const QFont*"] -> bool as "bool" { return self->bold(); })
+    }
+
+    /// Wrapper around [`setBold(bool enable)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setBold
+    pub fn set_bold(&mut self, enable: bool) {
+        cpp!(unsafe [self as "QFont*", enable as "bool"] { self->setBold(enable); })
+    }
+
+    /// Wrapper around [`italic()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#italic
+    pub fn italic(&self) -> bool {
+        cpp!(unsafe [self as "const QFont*"] -> bool as "bool" { return self->italic(); })
+    }
+
+    /// Wrapper around [`setItalic(bool enable)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setItalic
+    pub fn set_italic(&mut self, enable: bool) {
+        cpp!(unsafe [self as "QFont*", enable as "bool"] { self->setItalic(enable); })
+    }
+
+    /// Wrapper around [`underline()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#underline
+    pub fn underline(&self) -> bool {
+        cpp!(unsafe [self as "const QFont*"] -> bool as "bool" { return self->underline(); })
+    }
+
+    /// Wrapper around [`setUnderline(bool enable)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setUnderline
+    pub fn set_underline(&mut self, enable: bool) {
+        cpp!(unsafe [self as "QFont*", enable as "bool"] { self->setUnderline(enable); })
+    }
+
+    /// Wrapper around [`strikeOut()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#strikeOut
+    pub fn strike_out(&self) -> bool {
+        cpp!(unsafe [self as "const QFont*"] -> bool as "bool" { return self->strikeOut(); })
+    }
+
+    /// Wrapper around [`setStrikeOut(bool enable)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setStrikeOut
+    pub fn set_strike_out(&mut self, enable: bool) {
+        cpp!(unsafe [self as "QFont*", enable as "bool"] { self->setStrikeOut(enable); })
+    }
+
+    /// Wrapper around [`fixedPitch()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#fixedPitch
+    pub fn fixed_pitch(&self) -> bool {
+        cpp!(unsafe [self as "const QFont*"] -> bool as "bool" { return self->fixedPitch(); })
+    }
+
+    /// Wrapper around [`setFixedPitch(bool enable)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setFixedPitch
+    pub fn set_fixed_pitch(&mut self, enable: bool) {
+        cpp!(unsafe [self as "QFont*", enable as "bool"] { self->setFixedPitch(enable); })
+    }
+
+    /// Wrapper around [`letterSpacing()`][method] method, in pixels.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#letterSpacing
+    pub fn letter_spacing(&self) -> qreal {
+        cpp!(unsafe [self as "const QFont*"] -> qreal as "qreal" { return self->letterSpacing(); })
+    }
+
+    /// Wrapper around [`setLetterSpacing(QFont::AbsoluteSpacing, qreal spacing)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setLetterSpacing
+    pub fn set_letter_spacing(&mut self, spacing: qreal) {
+        cpp!(unsafe [self as "QFont*", spacing as "qreal"] {
+            self->setLetterSpacing(QFont::AbsoluteSpacing, spacing);
+        })
+    }
+}
+
+impl std::fmt::Debug for QFont {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let string = cpp!(unsafe [self as "const QFont*"] -> QString as "QString" {
+            return self->toString();
+        });
+        write!(f, "QFont({})", string)
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QFontMetricsF`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qfontmetricsf.html
+    #[derive(Clone, PartialEq)]
+    pub unsafe struct QFontMetricsF as "QFontMetricsF"
+);
+
+impl QFontMetricsF {
+    /// Wrapper around [`QFontMetricsF(const QFont &font)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qfontmetricsf.html#QFontMetricsF-1
+    pub fn new(font: &QFont) -> Self {
+        cpp!(unsafe [font as "const QFont*"] -> QFontMetricsF as "QFontMetricsF" {
+            return QFontMetricsF(*font);
+        })
+    }
+
+    /// Wrapper around [`ascent()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#ascent
+    pub fn ascent(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" { return self->ascent(); })
+    }
+
+    /// Wrapper around [`descent()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#descent
+    pub fn descent(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" { return self->descent(); })
+    }
+
+    /// Wrapper around [`height()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#height
+    pub fn height(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" { return self->height(); })
+    }
+
+    /// Wrapper around [`lineSpacing()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#lineSpacing
+    pub fn line_spacing(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" {
+            return self->lineSpacing();
+        })
+    }
+
+    /// Wrapper around [`averageCharWidth()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#averageCharWidth
+    pub fn average_char_width(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" {
+            return self->averageCharWidth();
+        })
+    }
+
+    /// Wrapper around [`horizontalAdvance(const QString &text)`][method] method.
+    ///
+    /// Returns the distance to the position of the next text drawn after `text`.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#horizontalAdvance
+    pub fn horizontal_advance(&self, text: QString) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*", text as "QString"] -> qreal as "qreal" {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 11, 0)
+            return self->horizontalAdvance(text);
+            #else
+            return self->width(text);
+            #endif
+        })
+    }
+
+    /// Wrapper around [`boundingRect(const QString &text)`][method] method.
+    ///
+    /// The rectangle is relative to the base line of the text.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#boundingRect
+    pub fn bounding_rect(&self, text: QString) -> QRectF {
+        cpp!(unsafe [self as "const QFontMetricsF*", text as "QString"] -> QRectF as "QRectF" {
+            return self->boundingRect(text);
+        })
+    }
+
+    /// Wrapper around [`tightBoundingRect(const QString &text)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#tightBoundingRect
+    pub fn tight_bounding_rect(&self, text: QString) -> QRectF {
+        cpp!(unsafe [self as "const QFontMetricsF*", text as "QString"] -> QRectF as "QRectF" {
+            return self->tightBoundingRect(text);
+        })
+    }
+
+    /// Wrapper around [`elidedText(const QString &text, Qt::TextElideMode mode, qreal width)`][method] method.
+    ///
+    /// Returns `text` unchanged if it fits in `width`.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#elidedText
+    pub fn elided_text(&self, text: QString, mode: TextElideMode, width: qreal) -> QString {
+        cpp!(unsafe [self as "const QFontMetricsF*", text as "QString", mode as "Qt::TextElideMode", width as "qreal"] -> QString as "QString" {
+            return self->elidedText(text, mode, width);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qfont() {
+        let mut font = QFont::from_family("Sans".into());
+        assert_eq!(font.family(), QString::from("Sans"));
+        font.set_pixel_size(12);
+        assert_eq!(font.pixel_size(), 12);
+        assert_eq!(font.point_size_f(), -1.);
+        font.set_point_size_f(10.5);
+        assert_eq!(font.point_size_f(), 10.5);
+        font.set_bold(true);
+        font.set_italic(true);
+        assert!(font.bold() && font.italic());
+        assert_ne!(font, QFont::from_family("Sans".into()));
+        assert_eq!(font.clone(), font);
+        font.set_underline(true);
+        font.set_strike_out(true);
+        font.set_fixed_pitch(true);
+        assert!(font.underline() && font.strike_out() && font.fixed_pitch());
+        font.set_letter_spacing(1.5);
+        assert_eq!(font.letter_spacing(), 1.5);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::internal_prelude::*;
+use crate::{QFont, QString, QStringList};
+
+cpp! {{
+    #include <QtGui/QFontDatabase>
+}}
+
+/// Bindings for [`QFontDatabase::SystemFont`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qfontdatabase.html#SystemFont-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SystemFont {
+    GeneralFont = 0,
+    FixedFont = 1,
+    TitleFont = 2,
+    SmallestReadableFont = 3,
+}
+
+/// Wrapper around the static functions of the [`QFontDatabase`][class] class.
+///
+/// Like the other font functions, they require a QGuiApplication.
+///
+/// [class]: https://doc.qt.io/qt-5/qfontdatabase.html
+pub enum QFontDatabase {}
+
+impl QFontDatabase {
+    /// Wrapper around [`addApplicationFont(const QString &fileName)`][method] method.
+    ///
+    /// The file name can be a resource path, like `":/fonts/MyFont.ttf"` for a font added with `qrc!`.
+    /// Returns the id of the font, or None if the font could not be loaded.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontdatabase.html#addApplicationFont
+    pub fn add_application_font(file_name: QString) -> Option<i32> {
+        let id = cpp!(unsafe [file_name as "QString"] -> i32 as "int" {
+            return QFontDatabase::addApplicationFont(file_name);
+        });
+        if id >= 0 {
+            Some(id)
+        } else {
+            None
+        }
+    }
+
+    /// Wrapper around [`addApplicationFontFromData(const QByteArray &fontData)`][method] method.
+    ///
+    /// Returns the id of the font, or None if the data is not a supported font.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontdatabase.html#addApplicationFontFromData
+    pub fn add_application_font_from_data(data: &[u8]) -> Option<i32> {
+        let data_ptr = data.as_ptr();
+        let data_len = data.len();
+        let id = cpp!(unsafe [data_ptr as "const char*", data_len as "size_t"] -> i32 as "int" {
+            return QFontDatabase::addApplicationFontFromData(QByteArray(data_ptr, int(data_len)));
+        });
+        if id >= 0 {
+            Some(id)
+        } else {
+            None
+        }
+    }
+
+    /// Wrapper around [`applicationFontFamilies(int id)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontdatabase.html#applicationFontFamilies
+    pub fn application_font_families(id: i32) -> QStringList {
+        cpp!(unsafe [id as "int"] -> QStringList as "QStringList" {
+            return QFontDatabase::applicationFontFamilies(id);
+        })
+    }
+
+    /// Wrapper around [`removeApplicationFont(int id)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontdatabase.html#removeApplicationFont
+    pub fn remove_application_font(id: i32) -> bool {
+        cpp!(unsafe [id as "int"] -> bool as "bool" {
+            return QFontDatabase::removeApplicationFont(id);
+        })
+    }
+
+    /// Wrapper around [`families()`][method] method.
+    ///
+    /// Returns the families of all the available fonts.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontdatabase.html#families
+    pub fn families() -> QStringList {
+        cpp!(unsafe [] -> QStringList as "QStringList" {
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            return QFontDatabase::families();
+            #else
+            return QFontDatabase().families();
+            #endif
+        })
+    }
+
+    /// Wrapper around [`systemFont(QFontDatabase::SystemFont type)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontdatabase.html#systemFont
+    pub fn system_font(font_type: SystemFont) -> QFont {
+        cpp!(unsafe [font_type as "QFontDatabase::SystemFont"] -> QFont as "QFont" {
+            return QFontDatabase::systemFont(font_type);
+        })
+    }
+}
@@ -0,0 +1,286 @@
+use crate::internal_prelude::*;
+use crate::{qreal, QBrush, QColor, QPointF};
+
+cpp! {{
+    #include <QtGui/QBrush>
+    #include <QtGui/QLinearGradient>
+    #include <QtGui/QRadialGradient>
+    #include <QtGui/QConicalGradient>
+}}
+
+/// Bindings for [`QGradient::Spread`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qgradient.html#Spread-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GradientSpread {
+    PadSpread = 0,
+    ReflectSpread = 1,
+    RepeatSpread = 2,
+}
+
+impl Default for GradientSpread {
+    fn default() -> Self {
+        GradientSpread::PadSpread
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QLinearGradient`][class] class.
+    ///
+    /// Convert it into a QBrush to paint with it.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qlineargradient.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QLinearGradient as "QLinearGradient"
+);
+
+impl QLinearGradient {
+    /// Wrapper around [`QLinearGradient(const QPointF &start, const QPointF &finalStop)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qlineargradient.html#QLinearGradient-1
+    pub fn new(start: QPointF, final_stop: QPointF) -> Self {
+        cpp!(unsafe [start as "QPointF", final_stop as "QPointF"] -> QLinearGradient as "QLinearGradient" {
+            return QLinearGradient(start, final_stop);
+        })
+    }
+
+    /// Wrapper around [`start()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qlineargradient.html#start
+    pub fn start(&self) -> QPointF {
+        cpp!(unsafe [self as "const QLinearGradient*"] -> QPointF as "QPointF" {
+            return self->start();
+        })
+    }
+
+    /// Wrapper around [`finalStop()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qlineargradient.html#finalStop
+    pub fn final_stop(&self) -> QPointF {
+        cpp!(unsafe [self as "const QLinearGradient*"] -> QPointF as "QPointF" {
+            return self->finalStop();
+        })
+    }
+
+    /// Wrapper around [`setColorAt(qreal position, const QColor &color)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qgradient.html#setColorAt
+    pub fn set_color_at(&mut self, position: qreal, color: QColor) {
+        cpp!(unsafe [self as "QLinearGradient*", position as "qreal", color as "QColor"] {
+            self->setColorAt(position, color);
+        })
+    }
+
+    /// Wrapper around [`spread()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qgradient.html#spread
+    pub fn spread(&self) -> GradientSpread {
+        cpp!(unsafe [self as "const QLinearGradient*"] -> GradientSpread as "QGradient::Spread" {
+            return self->spread();
+        })
+    }
+
+    /// Wrapper around [`setSpread(QGradient::Spread method)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qgradient.html#setSpread
+    pub fn set_spread(&mut self, spread: GradientSpread) {
+        cpp!(unsafe [self as "QLinearGradient*", spread as "QGradient::Spread"] {
+            self->setSpread(spread);
+        })
+    }
+}
+
+impl From<QLinearGradient> for QBrush {
+    fn from(gradient: QLinearGradient) -> QBrush {
+        cpp!(unsafe [gradient as "QLinearGradient"] -> QBrush as "QBrush" {
+            return QBrush(gradient);
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QRadialGradient`][class] class.
+    ///
+    /// Convert it into a QBrush to paint with it.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qradialgradient.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QRadialGradient as "QRadialGradient"
+);
+
+impl QRadialGradient {
+    /// Wrapper around [`QRadialGradient(const QPointF &center, qreal radius)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qradialgradient.html#QRadialGradient-3
+    pub fn new(center: QPointF, radius: qreal) -> Self {
+        cpp!(unsafe [center as "QPointF", radius as "qreal"] -> QRadialGradient as "QRadialGradient" {
+            return QRadialGradient(center, radius);
+        })
+    }
+
+    /// Wrapper around [`QRadialGradient(const QPointF &center, qreal radius, const QPointF &focalPoint)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qradialgradient.html#QRadialGradient-1
+    pub fn with_focal_point(center: QPointF, radius: qreal, focal_point: QPointF) -> Self {
+        cpp!(unsafe [center as "QPointF", radius as "qreal", focal_point as "QPointF"] -> QRadialGradient as "QRadialGradient" {
+            return QRadialGradient(center, radius, focal_point);
+        })
+    }
+
+    /// Wrapper around [`center()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qradialgradient.html#center
+    pub fn center(&self) -> QPointF {
+        cpp!(unsafe [self as "const QRadialGradient*"] -> QPointF as "QPointF" {
+            return self->center();
+        })
+    }
+
+    /// Wrapper around [`radius()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qradialgradient.html#radius
+    pub fn radius(&self) -> qreal {
+        cpp!(unsafe [self as "const QRadialGradient*"] -> qreal as "qreal" {
+            return self->radius();
+        })
+    }
+
+    /// Wrapper around [`focalPoint()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qradialgradient.html#focalPoint
+    pub fn focal_point(&self) -> QPointF {
+        cpp!(unsafe [self as "const QRadialGradient*"] -> QPointF as "QPointF" {
+            return self->focalPoint();
+        })
+    }
+
+    /// Wrapper around [`setColorAt(qreal position, const QColor &color)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qgradient.html#setColorAt
+    pub fn set_color_at(&mut self, position: qreal, color: QColor) {
+        cpp!(unsafe [self as "QRadialGradient*", position as "qreal", color as "QColor"] {
+            self->setColorAt(position, color);
+        })
+    }
+
+    /// Wrapper around [`spread()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qgradient.html#spread
+    pub fn spread(&self) -> GradientSpread {
+        cpp!(unsafe [self as "const QRadialGradient*"] -> GradientSpread as "QGradient::Spread" {
+            return self->spread();
+        })
+    }
+
+    /// Wrapper around [`setSpread(QGradient::Spread method)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qgradient.html#setSpread
+    pub fn set_spread(&mut self, spread: GradientSpread) {
+        cpp!(unsafe [self as "QRadialGradient*", spread as "QGradient::Spread"] {
+            self->setSpread(spread);
+        })
+    }
+}
+
+impl From<QRadialGradient> for QBrush {
+    fn from(gradient: QRadialGradient) -> QBrush {
+        cpp!(unsafe [gradient as "QRadialGradient"] -> QBrush as "QBrush" {
+            return QBrush(gradient);
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QConicalGradient`][class] class.
+    ///
+    /// Convert it into a QBrush to paint with it.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qconicalgradient.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QConicalGradient as "QConicalGradient"
+);
+
+impl QConicalGradient {
+    /// Wrapper around [`QConicalGradient(const QPointF &center, qreal angle)`][ctor] constructor.
+    ///
+    /// The angle is in degrees, counter-clockwise from the 3 o'clock position.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qconicalgradient.html#QConicalGradient-1
+    pub fn new(center: QPointF, angle: qreal) -> Self {
+        cpp!(unsafe [center as "QPointF", angle as "qreal"] -> QConicalGradient as "QConicalGradient" {
+            return QConicalGradient(center, angle);
+        })
+    }
+
+    /// Wrapper around [`center()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qconicalgradient.html#center
+    pub fn center(&self) -> QPointF {
+        cpp!(unsafe [self as "const QConicalGradient*"] -> QPointF as "QPointF" {
+            return self->center();
+        })
+    }
+
+    /// Wrapper around [`angle()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qconicalgradient.html#angle
+    pub fn angle(&self) -> qreal {
+        cpp!(unsafe [self as "const QConicalGradient*"] -> qreal as "qreal" {
+            return self->angle();
+        })
+    }
+
+    /// Wrapper around [`setColorAt(qreal position, const QColor &color)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qgradient.html#setColorAt
+    pub fn set_color_at(&mut self, position: qreal, color: QColor) {
+        cpp!(unsafe [self as "QConicalGradient*", position as "qreal", color as "QColor"] {
+            self->setColorAt(position, color);
+        })
+    }
+}
+
+impl From<QConicalGradient> for QBrush {
+    fn from(gradient: QConicalGradient) -> QBrush {
+        cpp!(unsafe [gradient as "QConicalGradient"] -> QBrush as "QBrush" {
+            return QBrush(gradient);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradients() {
+        let mut linear = QLinearGradient::new(QPointF { x: 0., y: 0. }, QPointF { x: 10., y: 0. });
+        assert_eq!(linear.start(), QPointF { x: 0., y: 0. });
+        assert_eq!(linear.final_stop(), QPointF { x: 10., y: 0. });
+        let copy = linear.clone();
+        linear.set_color_at(0., QColor::from_name("red"));
+        linear.set_color_at(1., QColor::from_name("blue"));
+        assert!(copy != linear);
+        linear.set_spread(GradientSpread::ReflectSpread);
+        assert_eq!(linear.spread(), GradientSpread::ReflectSpread);
+        let _brush: QBrush = linear.into();
+
+        let mut radial = QRadialGradient::with_focal_point(
+            QPointF { x: 5., y: 5. },
+            5.,
+            QPointF { x: 4., y: 4. },
+        );
+        assert_eq!(radial.center(), QPointF { x: 5., y: 5. });
+        assert_eq!(radial.radius(), 5.);
+        assert_eq!(radial.focal_point(), QPointF { x: 4., y: 4. });
+        radial.set_color_at(0.5, QColor::from_name("green"));
+        assert_eq!(radial.spread(), GradientSpread::PadSpread);
+        let _brush = QBrush::from(radial);
+
+        let conical = QConicalGradient::new(QPointF { x: 1., y: 2. }, 90.);
+        assert_eq!(conical.center(), QPointF { x: 1., y: 2. });
+        assert_eq!(conical.angle(), 90.);
+        let _brush = QBrush::from(conical);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::internal_prelude::*;
+use crate::{qreal, QPointF, QRectF};
+
+cpp! {{
+    #include <QtGui/QPainterPath>
+    #include <QtGui/QPolygonF>
+}}
+
+/// Bindings for [`Qt::FillRule`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#FillRule-enum
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FillRule {
+    OddEvenFill = 0,
+    WindingFill = 1,
+}
+
+impl Default for FillRule {
+    fn default() -> Self {
+        FillRule::OddEvenFill
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QPainterPath`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qpainterpath.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QPainterPath as "QPainterPath"
+);
+
+impl QPainterPath {
+    /// Wrapper around [`QPainterPath(const QPointF &startPoint)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qpainterpath.html#QPainterPath-1
+    pub fn from_start_point(start_point: QPointF) -> Self {
+        cpp!(unsafe [start_point as "QPointF"] -> QPainterPath as "QPainterPath" {
+            return QPainterPath(start_point);
+        })
+    }
+
+    /// Wrapper around [`moveTo(const QPointF &point)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#moveTo
+    pub fn move_to(&mut self, point: QPointF) {
+        cpp!(unsafe [self as "QPainterPath*", point as "QPointF"] { self->moveTo(point); })
+    }
+
+    /// Wrapper around [`lineTo(const QPointF &endPoint)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#lineTo
+    pub fn line_to(&mut self, end_point: QPointF) {
+        cpp!(unsafe [self as "QPainterPath*", end_point as "QPointF"] { self->lineTo(end_point); })
+    }
+
+    /// Wrapper around [`cubicTo(const QPointF &c1, const QPointF &c2, const QPointF &endPoint)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#cubicTo
+    pub fn cubic_to(&mut self, c1: QPointF, c2: QPointF, end_point: QPointF) {
+        cpp!(unsafe [self as "QPainterPath*", c1 as "QPointF", c2 as "QPointF", end_point as "QPointF"] {
+            self->cubicTo(c1, c2, end_point);
+        })
+    }
+
+    /// Wrapper around [`quadTo(const QPointF &c, const QPointF &endPoint)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#quadTo
+    pub fn quad_to(&mut self, c: QPointF, end_point: QPointF) {
+        cpp!(unsafe [self as "QPainterPath*", c as "QPointF", end_point as "QPointF"] {
+            self->quadTo(c, end_point);
+        })
+    }
+
+    /// Wrapper around [`arcTo(const QRectF &rectangle, qreal startAngle, qreal sweepLength)`][method] method.
+    ///
+    /// The angles are in degrees, counter-clockwise.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#arcTo
+    pub fn arc_to(&mut self, rectangle: QRectF, start_angle: qreal, sweep_length: qreal) {
+        cpp!(unsafe [self as "QPainterPath*", rectangle as "QRectF", start_angle as "qreal", sweep_length as "qreal"] {
+            self->arcTo(rectangle, start_angle, sweep_length);
+        })
+    }
+
+    /// Wrapper around [`closeSubpath()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#closeSubpath
+    pub fn close_subpath(&mut self) {
+        cpp!(unsafe [self as "QPainterPath*"] { self->closeSubpath(); })
+    }
+
+    /// Wrapper around [`addRect(const QRectF &rectangle)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#addRect
+    pub fn add_rect(&mut self, rectangle: QRectF) {
+        cpp!(unsafe [self as "QPainterPath*", rectangle as "QRectF"] { self->addRect(rectangle); })
+    }
+
+    /// Wrapper around [`addRoundedRect(const QRectF &rect, qreal xRadius, qreal yRadius)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#addRoundedRect
+    pub fn add_rounded_rect(&mut self, rect: QRectF, x_radius: qreal, y_radius: qreal) {
+        cpp!(unsafe [self as "QPainterPath*", rect as "QRectF", x_radius as "qreal", y_radius as "qreal"] {
+            self->addRoundedRect(rect, x_radius, y_radius);
+        })
+    }
+
+    /// Wrapper around [`addEllipse(const QRectF &boundingRectangle)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#addEllipse
+    pub fn add_ellipse(&mut self, bounding_rectangle: QRectF) {
+        cpp!(unsafe [self as "QPainterPath*", bounding_rectangle as "QRectF"] {
+            self->addEllipse(bounding_rectangle);
+        })
+    }
+
+    /// Wrapper around [`addPolygon(const QPolygonF &polygon)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#addPolygon
+    pub fn add_polygon(&mut self, points: &[QPointF]) {
+        let points_ptr = points.as_ptr();
+        let points_count = points.len() as u64;
+        cpp!(unsafe [self as "QPainterPath*", points_ptr as "const QPointF*", points_count as "uint64_t"] {
+            QPolygonF polygon;
+            polygon.reserve(points_count);
+            for (uint64_t i = 0; i < points_count; ++i)
+                polygon.append(points_ptr[i]);
+            self->addPolygon(polygon);
+        })
+    }
+
+    /// Wrapper around [`addPath(const QPainterPath &path)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#addPath
+    pub fn add_path(&mut self, path: &QPainterPath) {
+        cpp!(unsafe [self as "QPainterPath*", path as "const QPainterPath*"] { self->addPath(*path); })
+    }
+
+    /// Wrapper around [`currentPosition()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#currentPosition
+    pub fn current_position(&self) -> QPointF {
+        cpp!(unsafe [self as "const QPainterPath*"] -> QPointF as "QPointF" {
+            return self->currentPosition();
+        })
+    }
+
+    /// Wrapper around [`boundingRect()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#boundingRect
+    pub fn bounding_rect(&self) -> QRectF {
+        cpp!(unsafe [self as "const QPainterPath*"] -> QRectF as "QRectF" {
+            return self->boundingRect();
+        })
+    }
+
+    /// Wrapper around [`contains(const QPointF &point)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#contains
+    pub fn contains(&self, point: QPointF) -> bool {
+        cpp!(unsafe [self as "const QPainterPath*", point as "QPointF"] -> bool as "bool" {
+            return self->contains(point);
+        })
+    }
+
+    /// Wrapper around [`isEmpty()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#isEmpty
+    pub fn is_empty(&self) -> bool {
+        cpp!(unsafe [self as "const QPainterPath*"] -> bool as "bool" { return self->isEmpty(); })
+    }
+
+    /// Wrapper around [`length()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#length
+    pub fn length(&self) -> qreal {
+        cpp!(unsafe [self as "const QPainterPath*"] -> qreal as "qreal" { return self->length(); })
+    }
+
+    /// Wrapper around [`fillRule()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#fillRule
+    pub fn fill_rule(&self) -> FillRule {
+        cpp!(unsafe [self as "const QPainterPath*"] -> FillRule as "Qt::FillRule" {
+            return self->fillRule();
+        })
+    }
+
+    /// Wrapper around [`setFillRule(Qt::FillRule fillRule)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#setFillRule
+    pub fn set_fill_rule(&mut self, fill_rule: FillRule) {
+        cpp!(unsafe [self as "QPainterPath*", fill_rule as "Qt::FillRule"] {
+            self->setFillRule(fill_rule);
+        })
+    }
+
+    /// Wrapper around [`translated(const QPointF &offset)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpainterpath.html#translated-1
+    pub fn translated(&self, offset: QPointF) -> QPainterPath {
+        cpp!(unsafe [self as "const QPainterPath*", offset as "QPointF"] -> QPainterPath as "QPainterPath" {
+            return self->translated(offset);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qpainterpath() {
+        let mut path = QPainterPath::default();
+        assert!(path.is_empty());
+        path.move_to(QPointF { x: 0., y: 0. });
+        path.line_to(QPointF { x: 10., y: 0. });
+        path.line_to(QPointF { x: 10., y: 10. });
+        path.close_subpath();
+        assert!(!path.is_empty());
+        assert_eq!(path.bounding_rect(), QRectF { x: 0., y: 0., width: 10., height: 10. });
+        assert!(path.contains(QPointF { x: 8., y: 2. }));
+        assert!(!path.contains(QPointF { x: 2., y: 8. }));
+        assert!((path.length() - 20. - (200. as qreal).sqrt()).abs() < 1e-6);
+
+        let moved = path.translated(QPointF { x: 5., y: 5. });
+        assert_eq!(moved.bounding_rect(), QRectF { x: 5., y: 5., width: 10., height: 10. });
+        assert_ne!(moved, path);
+
+        let mut polygon = QPainterPath::default();
+        polygon.add_polygon(&[
+            QPointF { x: 0., y: 0. },
+            QPointF { x: 4., y: 0. },
+            QPointF { x: 4., y: 2. },
+        ]);
+        assert_eq!(polygon.bounding_rect(), QRectF { x: 0., y: 0., width: 4., height: 2. });
+        assert_eq!(polygon.current_position(), QPointF { x: 4., y: 2. });
+        polygon.set_fill_rule(FillRule::WindingFill);
+        assert_eq!(polygon.fill_rule(), FillRule::WindingFill);
+    }
+}
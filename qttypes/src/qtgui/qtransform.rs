@@ -0,0 +1,186 @@
+use crate::internal_prelude::*;
+use crate::{qreal, QPointF, QRectF};
+
+cpp! {{
+    #include <QtGui/QTransform>
+}}
+
+cpp_class!(
+    /// Wrapper around [`QTransform`][class] class.
+    ///
+    /// The default transform is the identity.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qtransform.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QTransform as "QTransform"
+);
+
+impl QTransform {
+    /// Wrapper around [`QTransform(qreal m11, qreal m12, qreal m21, qreal m22, qreal dx, qreal dy)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qtransform.html#QTransform-3
+    pub fn from_affine(
+        m11: qreal,
+        m12: qreal,
+        m21: qreal,
+        m22: qreal,
+        dx: qreal,
+        dy: qreal,
+    ) -> Self {
+        cpp!(unsafe [m11 as "qreal", m12 as "qreal", m21 as "qreal", m22 as "qreal", dx as "qreal", dy as "qreal"] -> QTransform as "QTransform" {
+            return QTransform(m11, m12, m21, m22, dx, dy);
+        })
+    }
+
+    /// Wrapper around [`fromTranslate(qreal dx, qreal dy)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#fromTranslate
+    pub fn from_translate(dx: qreal, dy: qreal) -> Self {
+        cpp!(unsafe [dx as "qreal", dy as "qreal"] -> QTransform as "QTransform" {
+            return QTransform::fromTranslate(dx, dy);
+        })
+    }
+
+    /// Wrapper around [`fromScale(qreal sx, qreal sy)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#fromScale
+    pub fn from_scale(sx: qreal, sy: qreal) -> Self {
+        cpp!(unsafe [sx as "qreal", sy as "qreal"] -> QTransform as "QTransform" {
+            return QTransform::fromScale(sx, sy);
+        })
+    }
+
+    /// Wrapper around [`translate(qreal dx, qreal dy)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#translate
+    pub fn translate(&mut self, dx: qreal, dy: qreal) {
+        cpp!(unsafe [self as "QTransform*", dx as "qreal", dy as "qreal"] { self->translate(dx, dy); })
+    }
+
+    /// Wrapper around [`scale(qreal sx, qreal sy)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#scale
+    pub fn scale(&mut self, sx: qreal, sy: qreal) {
+        cpp!(unsafe [self as "QTransform*", sx as "qreal", sy as "qreal"] { self->scale(sx, sy); })
+    }
+
+    /// Wrapper around [`rotate(qreal angle)`][method] method.
+    ///
+    /// The angle is in degrees, clockwise on screen.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#rotate
+    pub fn rotate(&mut self, angle: qreal) {
+        cpp!(unsafe [self as "QTransform*", angle as "qreal"] { self->rotate(angle); })
+    }
+
+    /// Wrapper around [`shear(qreal sh, qreal sv)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#shear
+    pub fn shear(&mut self, sh: qreal, sv: qreal) {
+        cpp!(unsafe [self as "QTransform*", sh as "qreal", sv as "qreal"] { self->shear(sh, sv); })
+    }
+
+    /// Wrapper around [`isIdentity()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#isIdentity
+    pub fn is_identity(&self) -> bool {
+        cpp!(unsafe [self as "const QTransform*"] -> bool as "bool" { return self->isIdentity(); })
+    }
+
+    /// Wrapper around [`inverted(bool *invertible)`][method] method.
+    ///
+    /// Returns None if the transform is not invertible.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#inverted
+    pub fn inverted(&self) -> Option<QTransform> {
+        let mut invertible = false;
+        let inverted = cpp!(unsafe [self as "const QTransform*", mut invertible as "bool"] -> QTransform as "QTransform" {
+            return self->inverted(&invertible);
+        });
+        if invertible {
+            Some(inverted)
+        } else {
+            None
+        }
+    }
+
+    /// Wrapper around [`map(const QPointF &p)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#map-6
+    pub fn map(&self, point: QPointF) -> QPointF {
+        cpp!(unsafe [self as "const QTransform*", point as "QPointF"] -> QPointF as "QPointF" {
+            return self->map(point);
+        })
+    }
+
+    /// Wrapper around [`mapRect(const QRectF &rectangle)`][method] method.
+    ///
+    /// Returns the bounding rectangle of the mapped rectangle.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qtransform.html#mapRect
+    pub fn map_rect(&self, rectangle: QRectF) -> QRectF {
+        cpp!(unsafe [self as "const QTransform*", rectangle as "QRectF"] -> QRectF as "QRectF" {
+            return self->mapRect(rectangle);
+        })
+    }
+}
+
+impl std::ops::Mul for QTransform {
+    type Output = QTransform;
+    /// Applies `self` first, then `other`, like in C++.
+    fn mul(self, other: QTransform) -> QTransform {
+        cpp!(unsafe [self as "QTransform", other as "QTransform"] -> QTransform as "QTransform" {
+            return self * other;
+        })
+    }
+}
+
+impl std::fmt::Debug for QTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut m: [qreal; 9] = [0.; 9];
+        let m_ptr = m.as_mut_ptr();
+        cpp!(unsafe [self as "const QTransform*", m_ptr as "qreal*"] {
+            qreal values[] = {
+                self->m11(), self->m12(), self->m13(),
+                self->m21(), self->m22(), self->m23(),
+                self->m31(), self->m32(), self->m33()
+            };
+            for (int i = 0; i < 9; ++i)
+                m_ptr[i] = values[i];
+        });
+        write!(f, "QTransform({:?})", m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qtransform() {
+        let mut t = QTransform::default();
+        assert!(t.is_identity());
+        t.translate(10., 20.);
+        t.scale(2., 3.);
+        assert!(!t.is_identity());
+        assert_eq!(t.map(QPointF { x: 1., y: 1. }), QPointF { x: 12., y: 23. });
+        assert_eq!(
+            t.map_rect(QRectF { x: 0., y: 0., width: 1., height: 1. }),
+            QRectF { x: 10., y: 20., width: 2., height: 3. }
+        );
+        assert_eq!(
+            t.clone(),
+            QTransform::from_scale(2., 3.) * QTransform::from_translate(10., 20.)
+        );
+        assert_eq!(t, QTransform::from_affine(2., 0., 0., 3., 10., 20.));
+
+        let inv = t.inverted().unwrap();
+        assert_eq!(inv.map(QPointF { x: 12., y: 23. }), QPointF { x: 1., y: 1. });
+        assert!(QTransform::from_scale(0., 1.).inverted().is_none());
+
+        let mut r = QTransform::default();
+        r.rotate(90.);
+        let p = r.map(QPointF { x: 1., y: 0. });
+        assert!(p.x.abs() < 1e-9 && (p.y - 1.).abs() < 1e-9, "{:?}", p);
+    }
+}